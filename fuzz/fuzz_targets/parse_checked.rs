@@ -0,0 +1,45 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use winmsg::{message_name, RawEvent, WindowEvent};
+
+// Messages whose variant fields cover every bit of both parameters, so a
+// successful parse must give back exactly the input.
+const EXACT: &[&str] = &[
+    "WM_CREATE", "WM_MOVE", "WM_SIZE", "WM_ACTIVATE", "WM_QUERYENDSESSION", "WM_ERASEBKGND",
+    "WM_ENDSESSION", "WM_SHOWWINDOW", "WM_ACTIVATEAPP", "WM_SETCURSOR", "WM_MOUSEACTIVATE",
+    "WM_GETMINMAXINFO", "WM_NEXTDLGCTL", "WM_SPOOLERSTATUS", "WM_VKEYTOITEM", "WM_CHARTOITEM",
+    "WM_SETFONT", "WM_GETOBJECT", "WM_COMPACTING", "WM_COMMNOTIFY", "WM_WINDOWPOSCHANGING",
+    "WM_WINDOWPOSCHANGED", "WM_POWER", "WM_COPYDATA", "WM_INPUTLANGCHANGEREQUEST",
+    "WM_INPUTLANGCHANGE", "WM_TCARD", "WM_HELP", "WM_GETICON", "WM_SETICON", "WM_NCCALCSIZE",
+    "WM_NCPAINT", "WM_NCACTIVATE", "WM_GETDLGCODE", "WM_NCUAHDRAWCAPTION", "WM_NCUAHDRAWFRAME",
+    "WM_INPUT_DEVICE_CHANGE", "WM_INPUT", "WM_KEYDOWN", "WM_KEYUP", "WM_CHAR", "WM_DEADCHAR",
+    "WM_SYSKEYDOWN", "WM_SYSKEYUP", "WM_SYSCHAR", "WM_SYSDEADCHAR", "WM_UNICHAR", "WM_TIMER",
+    "WM_GESTURE", "WM_GESTURENOTIFY", "WM_MENUSELECT", "WM_ENTERIDLE", "WM_MENUDRAG",
+    "WM_MENUGETOBJECT", "WM_MENUCOMMAND", "WM_CHANGEUISTATE", "WM_UPDATEUISTATE",
+    "WM_PARENTNOTIFY", "WM_ENTERMENULOOP", "WM_EXITMENULOOP", "WM_NEXTMENU", "WM_SIZING",
+    "WM_CAPTURECHANGED", "WM_MOVING", "WM_POWERBROADCAST", "WM_MDICREATE", "WM_MDIACTIVATE",
+    "WM_MDIGETACTIVE", "WM_DROPFILES", "WM_TOUCH", "WM_POINTERACTIVATE",
+    "WM_POINTERCAPTURECHANGED", "WM_IME_SETCONTEXT", "WM_IME_NOTIFY", "WM_WTSSESSION_CHANGE",
+    "WM_DPICHANGED", "WM_GETDPISCALEDSIZE", "WM_PAINTCLIPBOARD", "WM_ASKCBFORMATNAME",
+    "WM_PALETTEISCHANGING", "WM_PALETTECHANGED", "WM_APPCOMMAND", "WM_DWMNCRENDERINGCHANGED",
+    "WM_DWMCOLORIZATIONCOLORCHANGED", "WM_DWMWINDOWMAXIMIZEDCHANGE",
+    "WM_DWMSENDICONICTHUMBNAIL", "WM_GETTITLEBARINFOEX", "WM_COMMAND", "WM_CTLCOLORMSGBOX",
+    "WM_CTLCOLOREDIT", "WM_CTLCOLORLISTBOX", "WM_CTLCOLORBTN", "WM_CTLCOLORDLG",
+    "WM_CTLCOLORSCROLLBAR", "WM_CTLCOLORSTATIC"
+];
+
+fuzz_target!(|input: (u32, usize, isize)| {
+    let (msg, w_param, l_param) = input;
+    let event = WindowEvent::parse_checked(msg, w_param, l_param);
+    if let WindowEvent::Unknown(raw) = event {
+        assert_eq!(raw, RawEvent { msg, w_param, l_param });
+        return;
+    }
+    let raw = event.into_raw();
+    assert_eq!(raw.msg, msg);
+    if message_name(msg).map_or(false, |name| EXACT.contains(&name)) {
+        assert_eq!(raw, RawEvent { msg, w_param, l_param });
+    }
+    let again = WindowEvent::parse_checked(raw.msg, raw.w_param, raw.l_param);
+    assert_eq!(again.into_raw(), raw);
+});
@@ -1,17 +1,110 @@
-use std::ptr::NonNull;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::fmt;
+#[cfg(feature = "std")]
+use core::ptr::null_mut;
+use core::ptr::NonNull;
 use bitfield::bitfield;
-use winapi::shared::minwindef::{FALSE, LPARAM, TRUE, UINT, WORD, WPARAM};
-use winapi::shared::windef::{HDC, HICON, HWND, RECT};
-use winapi::um::winuser::*;
+use crate::sys::*;
+
+mod sys;
 
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct RawEvent {
     pub msg: UINT,
     pub w_param: WPARAM,
     pub l_param: LPARAM
 }
 
+/// The id ranges `WindowEvent::parse` tells apart.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MessageRange {
+    System,
+    User,
+    App,
+    String,
+    Reserved,
+}
+
+impl RawEvent {
+    pub const fn range(&self) -> MessageRange {
+        match self.msg {
+            0..WM_USER => MessageRange::System,
+            WM_USER..WM_APP => MessageRange::User,
+            WM_APP..0xC000 => MessageRange::App,
+            0xC000..0xFFFF => MessageRange::String,
+            _ => MessageRange::Reserved
+        }
+    }
+
+    /// `msg - WM_USER` for ids in the `WM_USER` range.
+    pub const fn user_offset(&self) -> Option<UINT> {
+        match self.range() {
+            MessageRange::User => Some(self.msg - WM_USER),
+            _ => None
+        }
+    }
+
+    /// The size of one event as written by `write_to`.
+    pub const ENCODED_LEN: usize = 4 + 8 + 8;
+
+    #[cfg(feature = "std")]
+    /// Writes `msg`, `w_param` and `l_param` as little-endian `u32`, `u64` and `i64`, so
+    /// recordings do not depend on the pointer width of the machine that made them.
+    pub fn write_to<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        let mut buf = [0; Self::ENCODED_LEN];
+        buf[..4].copy_from_slice(&self.msg.to_le_bytes());
+        buf[4..12].copy_from_slice(&(self.w_param as u64).to_le_bytes());
+        buf[12..].copy_from_slice(&(self.l_param as i64).to_le_bytes());
+        w.write_all(&buf)
+    }
+
+    #[cfg(feature = "std")]
+    /// Fails with `UnexpectedEof` on truncated input.
+    pub fn read_from<R: std::io::Read>(r: &mut R) -> std::io::Result<RawEvent> {
+        let (mut msg, mut w_param, mut l_param) = ([0; 4], [0; 8], [0; 8]);
+        r.read_exact(&mut msg)?;
+        r.read_exact(&mut w_param)?;
+        r.read_exact(&mut l_param)?;
+        Ok(RawEvent {
+            msg: u32::from_le_bytes(msg),
+            w_param: u64::from_le_bytes(w_param) as WPARAM,
+            l_param: i64::from_le_bytes(l_param) as LPARAM,
+        })
+    }
+
+    #[cfg(feature = "std")]
+    /// Writes the number of events as a little-endian `u64`, then each event.
+    pub fn write_all_to<W: std::io::Write>(events: &[RawEvent], w: &mut W) -> std::io::Result<()> {
+        w.write_all(&(events.len() as u64).to_le_bytes())?;
+        events.iter().try_for_each(|event| event.write_to(w))
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads what `write_all_to` wrote.
+    pub fn read_all_from<R: std::io::Read>(r: &mut R) -> std::io::Result<Vec<RawEvent>> {
+        let mut len = [0; 8];
+        r.read_exact(&mut len)?;
+        let len = u64::from_le_bytes(len);
+        let mut events = Vec::with_capacity(len.min(4096) as usize);
+        for _ in 0..len {
+            events.push(Self::read_from(r)?);
+        }
+        Ok(events)
+    }
+}
+
+/// Fixed-width hex, with the parameters as wide as a pointer:
+/// `msg=0x0400 wParam=0x0000000000000001 lParam=0x000000000012FF60` on 64-bit targets.
+impl fmt::Display for RawEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const WIDTH: usize = 2 + 2 * size_of::<usize>();
+        write!(f, "msg={:#06X} wParam={:#0w$X} lParam={:#0w$X}", self.msg, self.w_param, self.l_param as usize, w = WIDTH)
+    }
+}
+
 #[repr(C)]
 union UWM {
     raw: RawEvent,
@@ -19,8 +112,9 @@ union UWM {
 }
 
 mod modifiers {
-    use winapi::shared::minwindef::*;
-    use winapi::um::winuser::*;
+    use core::fmt;
+
+    use crate::sys::*;
 
     const CONTROL: WPARAM = MK_CONTROL;
     const L_BUTTON: WPARAM = MK_LBUTTON;
@@ -29,15 +123,259 @@ mod modifiers {
     const SHIFT: WPARAM = MK_SHIFT;
     const X_BUTTON1: WPARAM = MK_XBUTTON1;
     const X_BUTTON2: WPARAM = MK_XBUTTON2;
+    // Mouse messages carry no Alt flag and their `MK_*` flags fill the low word, so Alt
+    // takes a bit above it that only `from_keyboard_state` sets.
+    const ALT: WPARAM = 1 << 16;
+
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Copy, Clone, Eq, PartialEq)]
+    pub struct Modifiers(pub WPARAM);
+
+    impl Modifiers {
+        pub fn control(self) -> bool {
+            self.0 & CONTROL != 0
+        }
+
+        pub fn shift(self) -> bool {
+            self.0 & SHIFT != 0
+        }
+
+        pub fn alt(self) -> bool {
+            self.0 & ALT != 0
+        }
+
+        pub fn left_button(self) -> bool {
+            self.0 & L_BUTTON != 0
+        }
+
+        pub fn middle_button(self) -> bool {
+            self.0 & M_BUTTON != 0
+        }
+
+        pub fn right_button(self) -> bool {
+            self.0 & R_BUTTON != 0
+        }
+
+        pub fn x_button1(self) -> bool {
+            self.0 & X_BUTTON1 != 0
+        }
+
+        pub fn x_button2(self) -> bool {
+            self.0 & X_BUTTON2 != 0
+        }
+
+        #[cfg(feature = "std")]
+        /// Shift, Control and Alt as of the message being processed, for keyboard messages
+        /// which do not carry them.
+        pub fn from_keyboard_state() -> Self {
+            const VK_SHIFT_I: i32 = VK_SHIFT as _;
+            const VK_CONTROL_I: i32 = VK_CONTROL as _;
+            const VK_MENU_I: i32 = VK_MENU as _;
+            let held = |key| unsafe { GetKeyState(key) } < 0;
+            let mut modifiers = 0;
+            if held(VK_SHIFT_I) {
+                modifiers |= SHIFT;
+            }
+            if held(VK_CONTROL_I) {
+                modifiers |= CONTROL;
+            }
+            if held(VK_MENU_I) {
+                modifiers |= ALT;
+            }
+            Modifiers(modifiers)
+        }
+    }
+
+    impl fmt::Debug for Modifiers {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Modifiers")
+                .field("control", &self.control())
+                .field("shift", &self.shift())
+                .field("alt", &self.alt())
+                .field("left_button", &self.left_button())
+                .field("middle_button", &self.middle_button())
+                .field("right_button", &self.right_button())
+                .field("x_button1", &self.x_button1())
+                .field("x_button2", &self.x_button2())
+                .finish()
+        }
+    }
+}
+
+pub use modifiers::Modifiers;
+
+/// Declarations missing from `winapi`.
+#[cfg(not(feature = "windows-sys"))]
+mod ffi {
+    #![allow(non_snake_case, clippy::upper_case_acronyms)]
+
+    #[cfg(feature = "std")]
+    use winapi::ctypes::c_void;
+    use winapi::ctypes::c_int;
+    #[cfg(feature = "std")]
+    use winapi::shared::guiddef::GUID;
+    use winapi::shared::minwindef::*;
+    use winapi::shared::windef::*;
+    use winapi::um::winnt::{HANDLE, LPCSTR};
+
+    pub type HGESTUREINFO = HANDLE;
+
+    #[cfg(feature = "std")]
+    pub const GID_BEGIN: DWORD = 1;
+    #[cfg(feature = "std")]
+    pub const GID_END: DWORD = 2;
+    #[cfg(feature = "std")]
+    pub const GID_ZOOM: DWORD = 3;
+    #[cfg(feature = "std")]
+    pub const GID_PAN: DWORD = 4;
+    #[cfg(feature = "std")]
+    pub const GID_ROTATE: DWORD = 5;
+    #[cfg(feature = "std")]
+    pub const GID_TWOFINGERTAP: DWORD = 6;
+    #[cfg(feature = "std")]
+    pub const GID_PRESSANDTAP: DWORD = 7;
+
+    pub const HELP_TCARD_DATA: UINT = 0x0010;
+    pub const HELP_TCARD_OTHER_CALLER: UINT = 0x0011;
+
+    #[cfg(feature = "std")]
+    pub const GC_ZOOM: DWORD = 1;
+    #[cfg(feature = "std")]
+    pub const GC_PAN: DWORD = 1;
+    #[cfg(feature = "std")]
+    pub const GC_ROTATE: DWORD = 1;
+    #[cfg(feature = "std")]
+    pub const GC_TWOFINGERTAP: DWORD = 1;
+    #[cfg(feature = "std")]
+    pub const GC_PRESSANDTAP: DWORD = 1;
+
+    pub const IMN_CLOSESTATUSWINDOW: DWORD = 0x01;
+    pub const IMN_OPENSTATUSWINDOW: DWORD = 0x02;
+    pub const IMN_CHANGECANDIDATE: DWORD = 0x03;
+    pub const IMN_CLOSECANDIDATE: DWORD = 0x04;
+    pub const IMN_OPENCANDIDATE: DWORD = 0x05;
+    pub const IMN_SETCONVERSIONMODE: DWORD = 0x06;
+    pub const IMN_SETSENTENCEMODE: DWORD = 0x07;
+    pub const IMN_SETOPENSTATUS: DWORD = 0x08;
+    pub const IMN_SETCANDIDATEPOS: DWORD = 0x09;
+    pub const IMN_SETCOMPOSITIONFONT: DWORD = 0x0a;
+    pub const IMN_SETCOMPOSITIONWINDOW: DWORD = 0x0b;
+    pub const IMN_SETSTATUSWINDOWPOS: DWORD = 0x0c;
+    pub const IMN_GUIDELINE: DWORD = 0x0d;
+    pub const IMN_PRIVATE: DWORD = 0x0e;
+    pub const IMN_SOFTKBDDESTROYED: DWORD = 0x11;
+
+    #[cfg(feature = "std")]
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct GESTUREINFO {
+        pub cbSize: UINT,
+        pub dwFlags: DWORD,
+        pub dwID: DWORD,
+        pub hwndTarget: HWND,
+        pub ptsLocation: POINTS,
+        pub dwInstanceID: DWORD,
+        pub dwSequenceID: DWORD,
+        pub ullArguments: u64,
+        pub cbExtraArgs: UINT,
+    }
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct GESTURENOTIFYSTRUCT {
+        pub cbSize: UINT,
+        pub dwFlags: DWORD,
+        pub hwndTarget: HWND,
+        pub ptsLocation: POINTS,
+        pub dwInstanceID: DWORD,
+    }
+
+    #[cfg(feature = "std")]
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct GESTURECONFIG {
+        pub dwID: DWORD,
+        pub dwWant: DWORD,
+        pub dwBlock: DWORD,
+    }
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct MDICREATESTRUCTA {
+        pub szClass: LPCSTR,
+        pub szTitle: LPCSTR,
+        pub hOwner: HANDLE,
+        pub x: c_int,
+        pub y: c_int,
+        pub cx: c_int,
+        pub cy: c_int,
+        pub style: DWORD,
+        pub lParam: LPARAM,
+    }
+
+    #[cfg(feature = "std")]
+    #[link(name = "user32")]
+    extern "system" {
+        pub fn GetGestureInfo(hGestureInfo: HGESTUREINFO, pGestureInfo: *mut GESTUREINFO) -> BOOL;
+        pub fn CloseGestureInfoHandle(hGestureInfo: HGESTUREINFO) -> BOOL;
+        pub fn SetGestureConfig(hwnd: HWND, dwReserved: DWORD, cIDs: UINT, pGestureConfig: *const GESTURECONFIG, cbSize: UINT) -> BOOL;
+    }
+
+    #[cfg(feature = "std")]
+    #[link(name = "oleacc")]
+    extern "system" {
+        pub fn LresultFromObject(riid: *const GUID, wParam: WPARAM, punk: *mut c_void) -> LRESULT;
+    }
 }
 
+/// Which origin a mouse position is relative to: client messages use the client area,
+/// non-client and wheel messages the screen.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CoordSpace {
+    Client,
+    Screen,
+}
+
+/// Positions travel in `l_param` as two signed 16-bit words, which is what `x` and `y` hold,
+/// so negative coordinates from monitors left of or above the primary one keep their sign.
+/// Widening to `i32` would not add range: the message itself carries no more than 16 bits.
 #[repr(C, align(8))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Copy, Clone)]
 pub struct MousePos {
     pub x: i16,
     pub y: i16
 }
 
+impl MousePos {
+    /// The equivalent of `GET_X_LPARAM` and `GET_Y_LPARAM`.
+    pub const fn from_lparam(l_param: LPARAM) -> Self {
+        MousePos { x: l_param as i16, y: (l_param >> 16) as i16 }
+    }
+
+    pub const fn into_lparam(self) -> LPARAM {
+        (self.x as u16 as u32 | (self.y as u16 as u32) << 16) as LPARAM
+    }
+
+    #[cfg(feature = "std")]
+    fn into_space(self, from: CoordSpace, to: CoordSpace, window: HWND) -> Self {
+        let mut point = POINT { x: self.x as _, y: self.y as _ };
+        match (from, to) {
+            (CoordSpace::Screen, CoordSpace::Client) => unsafe { ScreenToClient(window, &mut point) },
+            (CoordSpace::Client, CoordSpace::Screen) => unsafe { ClientToScreen(window, &mut point) },
+            _ => return self
+        };
+        MousePos { x: point.x as _, y: point.y as _ }
+    }
+}
+
+impl fmt::Display for MousePos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
 #[repr(u32)]
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone)]
@@ -48,6 +386,488 @@ pub enum MouseActivation {
     NoActivateAndEat = MA_NOACTIVATEANDEAT,
 }
 
+impl MouseActivation {
+    pub fn from_code(code: u32) -> Option<Self> {
+        [
+            MouseActivation::Activate,
+            MouseActivation::ActivateAndEat,
+            MouseActivation::NoActivate,
+            MouseActivation::NoActivateAndEat
+        ].into_iter().find(|&activation| activation as u32 == code)
+    }
+
+    pub fn into_code(self) -> u32 {
+        self as u32
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct MouseActivateResult {
+    activate: bool,
+    eat: bool
+}
+
+impl MouseActivateResult {
+    pub fn activate() -> Self {
+        MouseActivateResult { activate: true, eat: false }
+    }
+
+    pub fn no_activate() -> Self {
+        MouseActivateResult { activate: false, eat: false }
+    }
+
+    pub fn and_eat(self) -> Self {
+        MouseActivateResult { eat: true, ..self }
+    }
+
+    pub fn activation(self) -> MouseActivation {
+        match (self.activate, self.eat) {
+            (true, false) => MouseActivation::Activate,
+            (true, true) => MouseActivation::ActivateAndEat,
+            (false, false) => MouseActivation::NoActivate,
+            (false, true) => MouseActivation::NoActivateAndEat,
+        }
+    }
+
+    pub fn into_lresult(self) -> LRESULT {
+        self.activation() as LRESULT
+    }
+
+    /// `WM_POINTERACTIVATE` has no eating variants, so only `activate` is honoured.
+    pub fn into_pointer_lresult(self) -> LRESULT {
+        (if self.activate { PA_ACTIVATE } else { PA_NOACTIVATE }) as LRESULT
+    }
+}
+
+#[repr(i16)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HitTest {
+    Error = HTERROR as _,
+    Transparent = HTTRANSPARENT as _,
+    Nowhere = HTNOWHERE as _,
+    Client = HTCLIENT as _,
+    Caption = HTCAPTION as _,
+    SysMenu = HTSYSMENU as _,
+    GrowBox = HTGROWBOX as _,
+    Menu = HTMENU as _,
+    HScroll = HTHSCROLL as _,
+    VScroll = HTVSCROLL as _,
+    MinButton = HTMINBUTTON as _,
+    MaxButton = HTMAXBUTTON as _,
+    Left = HTLEFT as _,
+    Right = HTRIGHT as _,
+    Top = HTTOP as _,
+    TopLeft = HTTOPLEFT as _,
+    TopRight = HTTOPRIGHT as _,
+    Bottom = HTBOTTOM as _,
+    BottomLeft = HTBOTTOMLEFT as _,
+    BottomRight = HTBOTTOMRIGHT as _,
+    Border = HTBORDER as _,
+    Object = HTOBJECT as _,
+    Close = HTCLOSE as _,
+    Help = HTHELP as _,
+}
+
+impl HitTest {
+    pub fn from_code(code: i16) -> Option<Self> {
+        [
+            HitTest::Error,
+            HitTest::Transparent,
+            HitTest::Nowhere,
+            HitTest::Client,
+            HitTest::Caption,
+            HitTest::SysMenu,
+            HitTest::GrowBox,
+            HitTest::Menu,
+            HitTest::HScroll,
+            HitTest::VScroll,
+            HitTest::MinButton,
+            HitTest::MaxButton,
+            HitTest::Left,
+            HitTest::Right,
+            HitTest::Top,
+            HitTest::TopLeft,
+            HitTest::TopRight,
+            HitTest::Bottom,
+            HitTest::BottomLeft,
+            HitTest::BottomRight,
+            HitTest::Border,
+            HitTest::Object,
+            HitTest::Close,
+            HitTest::Help
+        ].into_iter().find(|&hit_test| hit_test as i16 == code)
+    }
+
+    /// Places a screen position on a resizable frame covering `window_rect`, with
+    /// `border`-wide resize edges and a caption just below the top edge.
+    pub fn classify(pos: MousePos, window_rect: &RECT, border: i32, caption_height: i32) -> Self {
+        let (x, y) = (pos.x as i32, pos.y as i32);
+        if x < window_rect.left || x >= window_rect.right || y < window_rect.top || y >= window_rect.bottom {
+            return HitTest::Nowhere;
+        }
+        let left = x < window_rect.left + border;
+        let right = x >= window_rect.right - border;
+        let top = y < window_rect.top + border;
+        let bottom = y >= window_rect.bottom - border;
+        match (left, right, top, bottom) {
+            (true, _, true, _) => HitTest::TopLeft,
+            (_, true, true, _) => HitTest::TopRight,
+            (true, _, _, true) => HitTest::BottomLeft,
+            (_, true, _, true) => HitTest::BottomRight,
+            (true, ..) => HitTest::Left,
+            (_, true, ..) => HitTest::Right,
+            (_, _, true, _) => HitTest::Top,
+            (.., true) => HitTest::Bottom,
+            _ if y < window_rect.top + border + caption_height => HitTest::Caption,
+            _ => HitTest::Client
+        }
+    }
+
+    pub fn into_lresult(self) -> LRESULT {
+        self as LRESULT
+    }
+}
+
+#[repr(u16)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum VirtualKey {
+    Back = VK_BACK as _,
+    Tab = VK_TAB as _,
+    Clear = VK_CLEAR as _,
+    Return = VK_RETURN as _,
+    Shift = VK_SHIFT as _,
+    Control = VK_CONTROL as _,
+    Menu = VK_MENU as _,
+    Pause = VK_PAUSE as _,
+    Capital = VK_CAPITAL as _,
+    Kana = VK_KANA as _,
+    Junja = VK_JUNJA as _,
+    Final = VK_FINAL as _,
+    Kanji = VK_KANJI as _,
+    Escape = VK_ESCAPE as _,
+    Convert = VK_CONVERT as _,
+    NonConvert = VK_NONCONVERT as _,
+    Accept = VK_ACCEPT as _,
+    ModeChange = VK_MODECHANGE as _,
+    Space = VK_SPACE as _,
+    Prior = VK_PRIOR as _,
+    Next = VK_NEXT as _,
+    End = VK_END as _,
+    Home = VK_HOME as _,
+    Left = VK_LEFT as _,
+    Up = VK_UP as _,
+    Right = VK_RIGHT as _,
+    Down = VK_DOWN as _,
+    Select = VK_SELECT as _,
+    Print = VK_PRINT as _,
+    Execute = VK_EXECUTE as _,
+    Snapshot = VK_SNAPSHOT as _,
+    Insert = VK_INSERT as _,
+    Delete = VK_DELETE as _,
+    Help = VK_HELP as _,
+    Key0 = 0x30,
+    Key1 = 0x31,
+    Key2 = 0x32,
+    Key3 = 0x33,
+    Key4 = 0x34,
+    Key5 = 0x35,
+    Key6 = 0x36,
+    Key7 = 0x37,
+    Key8 = 0x38,
+    Key9 = 0x39,
+    A = 0x41,
+    B = 0x42,
+    C = 0x43,
+    D = 0x44,
+    E = 0x45,
+    F = 0x46,
+    G = 0x47,
+    H = 0x48,
+    I = 0x49,
+    J = 0x4a,
+    K = 0x4b,
+    L = 0x4c,
+    M = 0x4d,
+    N = 0x4e,
+    O = 0x4f,
+    P = 0x50,
+    Q = 0x51,
+    R = 0x52,
+    S = 0x53,
+    T = 0x54,
+    U = 0x55,
+    V = 0x56,
+    W = 0x57,
+    X = 0x58,
+    Y = 0x59,
+    Z = 0x5a,
+    LWin = VK_LWIN as _,
+    RWin = VK_RWIN as _,
+    Apps = VK_APPS as _,
+    Sleep = VK_SLEEP as _,
+    Numpad0 = VK_NUMPAD0 as _,
+    Numpad1 = VK_NUMPAD1 as _,
+    Numpad2 = VK_NUMPAD2 as _,
+    Numpad3 = VK_NUMPAD3 as _,
+    Numpad4 = VK_NUMPAD4 as _,
+    Numpad5 = VK_NUMPAD5 as _,
+    Numpad6 = VK_NUMPAD6 as _,
+    Numpad7 = VK_NUMPAD7 as _,
+    Numpad8 = VK_NUMPAD8 as _,
+    Numpad9 = VK_NUMPAD9 as _,
+    Multiply = VK_MULTIPLY as _,
+    Add = VK_ADD as _,
+    Separator = VK_SEPARATOR as _,
+    Subtract = VK_SUBTRACT as _,
+    Decimal = VK_DECIMAL as _,
+    Divide = VK_DIVIDE as _,
+    F1 = VK_F1 as _,
+    F2 = VK_F2 as _,
+    F3 = VK_F3 as _,
+    F4 = VK_F4 as _,
+    F5 = VK_F5 as _,
+    F6 = VK_F6 as _,
+    F7 = VK_F7 as _,
+    F8 = VK_F8 as _,
+    F9 = VK_F9 as _,
+    F10 = VK_F10 as _,
+    F11 = VK_F11 as _,
+    F12 = VK_F12 as _,
+    F13 = VK_F13 as _,
+    F14 = VK_F14 as _,
+    F15 = VK_F15 as _,
+    F16 = VK_F16 as _,
+    F17 = VK_F17 as _,
+    F18 = VK_F18 as _,
+    F19 = VK_F19 as _,
+    F20 = VK_F20 as _,
+    F21 = VK_F21 as _,
+    F22 = VK_F22 as _,
+    F23 = VK_F23 as _,
+    F24 = VK_F24 as _,
+    NumLock = VK_NUMLOCK as _,
+    Scroll = VK_SCROLL as _,
+    LShift = VK_LSHIFT as _,
+    RShift = VK_RSHIFT as _,
+    LControl = VK_LCONTROL as _,
+    RControl = VK_RCONTROL as _,
+    LMenu = VK_LMENU as _,
+    RMenu = VK_RMENU as _,
+    BrowserBack = VK_BROWSER_BACK as _,
+    BrowserForward = VK_BROWSER_FORWARD as _,
+    BrowserRefresh = VK_BROWSER_REFRESH as _,
+    BrowserStop = VK_BROWSER_STOP as _,
+    BrowserSearch = VK_BROWSER_SEARCH as _,
+    BrowserFavorites = VK_BROWSER_FAVORITES as _,
+    BrowserHome = VK_BROWSER_HOME as _,
+    VolumeMute = VK_VOLUME_MUTE as _,
+    VolumeDown = VK_VOLUME_DOWN as _,
+    VolumeUp = VK_VOLUME_UP as _,
+    MediaNextTrack = VK_MEDIA_NEXT_TRACK as _,
+    MediaPrevTrack = VK_MEDIA_PREV_TRACK as _,
+    MediaStop = VK_MEDIA_STOP as _,
+    MediaPlayPause = VK_MEDIA_PLAY_PAUSE as _,
+    LaunchMail = VK_LAUNCH_MAIL as _,
+    LaunchMediaSelect = VK_LAUNCH_MEDIA_SELECT as _,
+    LaunchApp1 = VK_LAUNCH_APP1 as _,
+    LaunchApp2 = VK_LAUNCH_APP2 as _,
+    Oem1 = VK_OEM_1 as _,
+    OemPlus = VK_OEM_PLUS as _,
+    OemComma = VK_OEM_COMMA as _,
+    OemMinus = VK_OEM_MINUS as _,
+    OemPeriod = VK_OEM_PERIOD as _,
+    Oem2 = VK_OEM_2 as _,
+    Oem3 = VK_OEM_3 as _,
+    Oem4 = VK_OEM_4 as _,
+    Oem5 = VK_OEM_5 as _,
+    Oem6 = VK_OEM_6 as _,
+    Oem7 = VK_OEM_7 as _,
+    Oem8 = VK_OEM_8 as _,
+    Oem102 = VK_OEM_102 as _,
+    ProcessKey = VK_PROCESSKEY as _,
+    Packet = VK_PACKET as _,
+    Attn = VK_ATTN as _,
+    CrSel = VK_CRSEL as _,
+    ExSel = VK_EXSEL as _,
+    ErEof = VK_EREOF as _,
+    Play = VK_PLAY as _,
+    Zoom = VK_ZOOM as _,
+    Pa1 = VK_PA1 as _,
+    OemClear = VK_OEM_CLEAR as _,
+}
+
+impl VirtualKey {
+    pub fn from_code(code: u16) -> Option<Self> {
+        [
+            VirtualKey::Back,
+            VirtualKey::Tab,
+            VirtualKey::Clear,
+            VirtualKey::Return,
+            VirtualKey::Shift,
+            VirtualKey::Control,
+            VirtualKey::Menu,
+            VirtualKey::Pause,
+            VirtualKey::Capital,
+            VirtualKey::Kana,
+            VirtualKey::Junja,
+            VirtualKey::Final,
+            VirtualKey::Kanji,
+            VirtualKey::Escape,
+            VirtualKey::Convert,
+            VirtualKey::NonConvert,
+            VirtualKey::Accept,
+            VirtualKey::ModeChange,
+            VirtualKey::Space,
+            VirtualKey::Prior,
+            VirtualKey::Next,
+            VirtualKey::End,
+            VirtualKey::Home,
+            VirtualKey::Left,
+            VirtualKey::Up,
+            VirtualKey::Right,
+            VirtualKey::Down,
+            VirtualKey::Select,
+            VirtualKey::Print,
+            VirtualKey::Execute,
+            VirtualKey::Snapshot,
+            VirtualKey::Insert,
+            VirtualKey::Delete,
+            VirtualKey::Help,
+            VirtualKey::Key0,
+            VirtualKey::Key1,
+            VirtualKey::Key2,
+            VirtualKey::Key3,
+            VirtualKey::Key4,
+            VirtualKey::Key5,
+            VirtualKey::Key6,
+            VirtualKey::Key7,
+            VirtualKey::Key8,
+            VirtualKey::Key9,
+            VirtualKey::A,
+            VirtualKey::B,
+            VirtualKey::C,
+            VirtualKey::D,
+            VirtualKey::E,
+            VirtualKey::F,
+            VirtualKey::G,
+            VirtualKey::H,
+            VirtualKey::I,
+            VirtualKey::J,
+            VirtualKey::K,
+            VirtualKey::L,
+            VirtualKey::M,
+            VirtualKey::N,
+            VirtualKey::O,
+            VirtualKey::P,
+            VirtualKey::Q,
+            VirtualKey::R,
+            VirtualKey::S,
+            VirtualKey::T,
+            VirtualKey::U,
+            VirtualKey::V,
+            VirtualKey::W,
+            VirtualKey::X,
+            VirtualKey::Y,
+            VirtualKey::Z,
+            VirtualKey::LWin,
+            VirtualKey::RWin,
+            VirtualKey::Apps,
+            VirtualKey::Sleep,
+            VirtualKey::Numpad0,
+            VirtualKey::Numpad1,
+            VirtualKey::Numpad2,
+            VirtualKey::Numpad3,
+            VirtualKey::Numpad4,
+            VirtualKey::Numpad5,
+            VirtualKey::Numpad6,
+            VirtualKey::Numpad7,
+            VirtualKey::Numpad8,
+            VirtualKey::Numpad9,
+            VirtualKey::Multiply,
+            VirtualKey::Add,
+            VirtualKey::Separator,
+            VirtualKey::Subtract,
+            VirtualKey::Decimal,
+            VirtualKey::Divide,
+            VirtualKey::F1,
+            VirtualKey::F2,
+            VirtualKey::F3,
+            VirtualKey::F4,
+            VirtualKey::F5,
+            VirtualKey::F6,
+            VirtualKey::F7,
+            VirtualKey::F8,
+            VirtualKey::F9,
+            VirtualKey::F10,
+            VirtualKey::F11,
+            VirtualKey::F12,
+            VirtualKey::F13,
+            VirtualKey::F14,
+            VirtualKey::F15,
+            VirtualKey::F16,
+            VirtualKey::F17,
+            VirtualKey::F18,
+            VirtualKey::F19,
+            VirtualKey::F20,
+            VirtualKey::F21,
+            VirtualKey::F22,
+            VirtualKey::F23,
+            VirtualKey::F24,
+            VirtualKey::NumLock,
+            VirtualKey::Scroll,
+            VirtualKey::LShift,
+            VirtualKey::RShift,
+            VirtualKey::LControl,
+            VirtualKey::RControl,
+            VirtualKey::LMenu,
+            VirtualKey::RMenu,
+            VirtualKey::BrowserBack,
+            VirtualKey::BrowserForward,
+            VirtualKey::BrowserRefresh,
+            VirtualKey::BrowserStop,
+            VirtualKey::BrowserSearch,
+            VirtualKey::BrowserFavorites,
+            VirtualKey::BrowserHome,
+            VirtualKey::VolumeMute,
+            VirtualKey::VolumeDown,
+            VirtualKey::VolumeUp,
+            VirtualKey::MediaNextTrack,
+            VirtualKey::MediaPrevTrack,
+            VirtualKey::MediaStop,
+            VirtualKey::MediaPlayPause,
+            VirtualKey::LaunchMail,
+            VirtualKey::LaunchMediaSelect,
+            VirtualKey::LaunchApp1,
+            VirtualKey::LaunchApp2,
+            VirtualKey::Oem1,
+            VirtualKey::OemPlus,
+            VirtualKey::OemComma,
+            VirtualKey::OemMinus,
+            VirtualKey::OemPeriod,
+            VirtualKey::Oem2,
+            VirtualKey::Oem3,
+            VirtualKey::Oem4,
+            VirtualKey::Oem5,
+            VirtualKey::Oem6,
+            VirtualKey::Oem7,
+            VirtualKey::Oem8,
+            VirtualKey::Oem102,
+            VirtualKey::ProcessKey,
+            VirtualKey::Packet,
+            VirtualKey::Attn,
+            VirtualKey::CrSel,
+            VirtualKey::ExSel,
+            VirtualKey::ErEof,
+            VirtualKey::Play,
+            VirtualKey::Zoom,
+            VirtualKey::Pa1,
+            VirtualKey::OemClear
+        ].into_iter().find(|&key| key as u16 == code)
+    }
+}
+
 #[repr(u16)]
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone)]
@@ -57,19 +877,72 @@ pub enum WindowActivation {
     Inactive = WA_INACTIVE,
 }
 
+impl WindowActivation {
+    pub fn from_code(code: u16) -> Option<Self> {
+        [
+            WindowActivation::Active,
+            WindowActivation::ClickActive,
+            WindowActivation::Inactive
+        ].into_iter().find(|&activation| activation as u16 == code)
+    }
+
+    pub fn into_code(self) -> u16 {
+        self as u16
+    }
+}
+
 bitfield! {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(Copy, Clone)]
     pub struct KeyInfo(u64); impl Debug;
     u32;
-    pub repeat_count, _: 15, 0;
-    pub scan_code, _: 23, 16;
-    pub extended, _: 24;
+    pub repeat_count, set_repeat_count: 15, 0;
+    pub scan_code, set_scan_code: 23, 16;
+    pub extended, set_extended: 24;
     _reserved, _: 28, 25;
-    pub context_code, _: 29;
-    pub previous_state, _: 30;
-    pub transition_state, _: 31;
+    pub context_code, set_context_code: 29;
+    pub previous_state, set_previous_state: 30;
+    pub transition_state, set_transition_state: 31;
+}
+
+impl KeyInfo {
+    pub fn new(repeat_count: u32, scan_code: u32, extended: bool, context: bool, previous: bool, transition: bool) -> Self {
+        let mut info = KeyInfo(0);
+        info.set_repeat_count(repeat_count);
+        info.set_scan_code(scan_code);
+        info.set_extended(extended);
+        info.set_context_code(context);
+        info.set_previous_state(previous);
+        info.set_transition_state(transition);
+        info
+    }
+
+    pub fn into_lparam(self) -> LPARAM {
+        self.0 as LPARAM
+    }
+
+    /// The key was already down, so this is an auto-repeat.
+    pub fn is_repeat(&self) -> bool {
+        self.previous_state()
+    }
+
+    /// The key is being released; always set for key-up messages.
+    pub fn is_release(&self) -> bool {
+        self.transition_state()
+    }
+
+    /// ALT was held; only meaningful for `WM_SYSKEYDOWN` and `WM_SYSKEYUP`.
+    pub fn alt_held(&self) -> bool {
+        self.context_code()
+    }
+
+    /// An extended key such as the right-hand ALT and CTRL, or the arrow keys outside the numpad.
+    pub fn is_extended(&self) -> bool {
+        self.extended()
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Copy, Clone)]
 pub struct KeyMessage {
     pub up: bool,
@@ -78,11 +951,68 @@ pub struct KeyMessage {
     pub info: KeyInfo
 }
 
+impl KeyMessage {
+    pub fn repeats(&self) -> impl Iterator<Item = KeyMessage> {
+        let count = if self.up { 1 } else { self.info.repeat_count().max(1) };
+        let mut single = *self;
+        single.info.set_repeat_count(1);
+        core::iter::repeat_n(single, count as usize)
+    }
+
+    pub fn into_window_message(self) -> WindowMessage {
+        let (key_code, info) = (self.code, self.info);
+        match (self.sys, self.up) {
+            (false, false) => WindowMessage::KeyDown { key_code, info },
+            (false, true) => WindowMessage::KeyUp { key_code, info },
+            (true, false) => WindowMessage::SysKeyDown { key_code, info },
+            (true, true) => WindowMessage::SysKeyUp { key_code, info },
+        }
+    }
+}
+
+impl fmt::Display for KeyMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sys = if self.sys { "Sys" } else { "" };
+        let action = if self.up { "Up" } else { "Down" };
+        match VirtualKey::from_code(self.code as u16) {
+            Some(key) => write!(f, "{}Key{} {:?}", sys, action, key),
+            None => write!(f, "{}Key{} 0x{:02X}", sys, action, self.code)
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub enum MouseButton {
     Left, Right, Middle, X(WORD)
 }
 
+impl MouseButton {
+    /// The `MK_*` bit reporting the button as held in mouse message modifiers.
+    pub fn mk_flag(&self) -> WPARAM {
+        match *self {
+            MouseButton::Left => MK_LBUTTON,
+            MouseButton::Right => MK_RBUTTON,
+            MouseButton::Middle => MK_MBUTTON,
+            MouseButton::X(XBUTTON1) => MK_XBUTTON1,
+            MouseButton::X(XBUTTON2) => MK_XBUTTON2,
+            MouseButton::X(_) => 0
+        }
+    }
+}
+
+impl fmt::Display for MouseButton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MouseButton::Left => f.write_str("Left"),
+            MouseButton::Right => f.write_str("Right"),
+            MouseButton::Middle => f.write_str("Middle"),
+            MouseButton::X(button) => write!(f, "X{}", button)
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub enum MouseButtonAction {
     Down,
@@ -90,626 +1020,5907 @@ pub enum MouseButtonAction {
     DoubleClick
 }
 
+impl fmt::Display for MouseButtonAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MouseButtonAction::Down => f.write_str("Down"),
+            MouseButtonAction::Up => f.write_str("Up"),
+            MouseButtonAction::DoubleClick => f.write_str("DoubleClick")
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Copy, Clone)]
 pub struct MouseButtonMessage {
     pub action: MouseButtonAction,
     pub button: MouseButton,
     pub pos: MousePos,
     pub modifiers: WPARAM,
+    pub space: CoordSpace,
 }
 
-#[repr(u64)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone)]
-pub enum IconSize {
-    Small = ICON_SMALL as _,
-    Big = ICON_BIG as _,
-    Small2 = ICON_SMALL2 as _,
+impl MouseButtonMessage {
+    /// Buttons other than `button` that `modifiers` reports as held.
+    pub fn other_buttons_held(&self) -> impl Iterator<Item = MouseButton> {
+        let (button, modifiers) = (self.button, self.modifiers);
+        [
+            MouseButton::Left,
+            MouseButton::Right,
+            MouseButton::Middle,
+            MouseButton::X(XBUTTON1),
+            MouseButton::X(XBUTTON2)
+        ].into_iter().filter(move |&other| other != button && modifiers & other.mk_flag() != 0)
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn to_client(self, window: HWND) -> Self {
+        MouseButtonMessage { pos: self.pos.into_space(self.space, CoordSpace::Client, window), space: CoordSpace::Client, ..self }
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn to_screen(self, window: HWND) -> Self {
+        MouseButtonMessage { pos: self.pos.into_space(self.space, CoordSpace::Screen, window), space: CoordSpace::Screen, ..self }
+    }
+
+    /// `pos` is put back as is, so convert it `to_client` first.
+    pub fn into_window_message(self) -> WindowMessage {
+        let (modifiers, pos) = (self.modifiers, self.pos);
+        match (self.button, self.action) {
+            (MouseButton::Left, MouseButtonAction::Down) => WindowMessage::LButtonDown { modifiers, pos },
+            (MouseButton::Left, MouseButtonAction::Up) => WindowMessage::LButtonUp { modifiers, pos },
+            (MouseButton::Left, MouseButtonAction::DoubleClick) => WindowMessage::LButtonDblClk { modifiers, pos },
+            (MouseButton::Right, MouseButtonAction::Down) => WindowMessage::RButtonDown { modifiers, pos },
+            (MouseButton::Right, MouseButtonAction::Up) => WindowMessage::RButtonUp { modifiers, pos },
+            (MouseButton::Right, MouseButtonAction::DoubleClick) => WindowMessage::RButtonDblClk { modifiers, pos },
+            (MouseButton::Middle, MouseButtonAction::Down) => WindowMessage::MButtonDown { modifiers, pos },
+            (MouseButton::Middle, MouseButtonAction::Up) => WindowMessage::MButtonUp { modifiers, pos },
+            (MouseButton::Middle, MouseButtonAction::DoubleClick) => WindowMessage::MButtonDblClk { modifiers, pos },
+            (MouseButton::X(button), MouseButtonAction::Down) => WindowMessage::XButtonDown { _align: [], modifiers: modifiers as _, button, pos },
+            (MouseButton::X(button), MouseButtonAction::Up) => WindowMessage::XButtonUp { _align: [], modifiers: modifiers as _, button, pos },
+            (MouseButton::X(button), MouseButtonAction::DoubleClick) => WindowMessage::XButtonDblClk { _align: [], modifiers: modifiers as _, button, pos },
+        }
+    }
 }
 
-#[repr(isize)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone)]
-pub enum WindowShown {
-    OtherUnZoom = SW_OTHERUNZOOM,
-    OtherZoom = SW_OTHERZOOM,
-    ParentClosing = SW_PARENTCLOSING,
-    ParentOpening = SW_PARENTOPENING,
+impl fmt::Display for MouseButtonMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} @ {}", self.button, self.action, self.pos)
+    }
 }
 
-#[repr(usize)]
-#[non_exhaustive]
+/// One UTF-16 code unit of typed text. A character outside the BMP arrives as two messages;
+/// `CharDecoder` pairs them. `dead` marks the accent of a dead key, which composes with the
+/// character after it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Copy, Clone)]
-pub enum WindowResizing {
-    MaxHide = SIZE_MAXHIDE,
-    Maximized = SIZE_MAXIMIZED,
-    MaxShow = SIZE_MAXSHOW,
-    Minimized = SIZE_MINIMIZED,
-    Restored = SIZE_RESTORED,
+pub struct CharMessage {
+    pub code_unit: u16,
+    pub info: KeyInfo,
+    pub sys: bool,
+    pub dead: bool
 }
 
-#[repr(usize)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone)]
-pub enum PowerEvent {
-    ApmPowerStatusChange = PBT_APMPOWERSTATUSCHANGE,
-    ApmResumeAutomatic = PBT_APMRESUMEAUTOMATIC,
-    ApmResumeSuspend = PBT_APMRESUMESUSPEND,
-    ApmSuspend = PBT_APMSUSPEND,
-    PowerSettingsChange = PBT_POWERSETTINGCHANGE,
+/// Turns a stream of `CharMessage`s into `char`s, pairing surrogates and applying a pending
+/// dead key. `TranslateMessage` normally composes the character itself; the table here covers
+/// the Latin-1 accents for streams that were not translated, such as replayed ones.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct CharDecoder {
+    high_surrogate: Option<u16>,
+    dead_key: Option<char>
 }
 
-#[repr(i32)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone)]
-pub enum GwlStyle {
-    ExStyle = GWL_EXSTYLE,
-    Style = GWL_STYLE,
+impl CharDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The accent waiting for the next character, for showing a composition preview.
+    pub fn pending_dead_key(&self) -> Option<char> {
+        self.dead_key
+    }
+
+    /// Nothing while a dead key or the first half of a surrogate pair is pending, nor for an
+    /// unpaired surrogate. A dead key followed by a space gives the accent on its own, and one
+    /// followed by a character it does not compose with gives the accent and then the
+    /// character, as `TranslateMessage` does.
+    pub fn feed(&mut self, message: CharMessage) -> impl Iterator<Item = char> {
+        let (first, second) = self.decode(message).unzip();
+        first.into_iter().chain(second.flatten())
+    }
+
+    fn decode(&mut self, message: CharMessage) -> Option<(char, Option<char>)> {
+        let unit = message.code_unit;
+        if message.dead {
+            self.high_surrogate = None;
+            self.dead_key = char::from_u32(unit as u32);
+            return None;
+        }
+        let c = match unit {
+            0xD800..=0xDBFF => {
+                self.high_surrogate = Some(unit);
+                return None;
+            },
+            0xDC00..=0xDFFF => {
+                let high = self.high_surrogate.take()?;
+                char::decode_utf16([high, unit]).next()?.ok()?
+            },
+            _ => {
+                self.high_surrogate = None;
+                char::from_u32(unit as u32)?
+            }
+        };
+        Some(match self.dead_key.take() {
+            Some(accent) if c == ' ' => (accent, None),
+            Some(accent) => match compose_dead_key(accent, c) {
+                Some(composed) => (composed, None),
+                None => (accent, Some(c))
+            },
+            None => (c, None)
+        })
+    }
 }
 
-#[cfg(target_pointer_width = "64")]
-const _NC_SIZE_ASSERT: [u8; 16] = [0; size_of::<NcSizeParams>()];
-#[cfg(target_pointer_width = "32")]
-const _NC_SIZE_ASSERT: [u8; 8] = [0; size_of::<NcSizeParams>()];
+fn compose_dead_key(accent: char, base: char) -> Option<char> {
+    let (bases, composed) = match accent {
+        '`' => ("AEIOUaeiou", "ÀÈÌÒÙàèìòù"),
+        '\u{B4}' | '\'' => ("AEIOUYaeiouy", "ÁÉÍÓÚÝáéíóúý"),
+        '^' => ("AEIOUaeiou", "ÂÊÎÔÛâêîôû"),
+        '~' => ("ANOano", "ÃÑÕãñõ"),
+        '\u{A8}' | '"' => ("AEIOUaeiouy", "ÄËÏÖÜäëïöüÿ"),
+        '\u{B8}' => ("Cc", "Çç"),
+        _ => return None
+    };
+    let index = bases.chars().position(|b| b == base)?;
+    composed.chars().nth(index)
+}
 
-#[repr(usize)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone)]
-pub enum NcSizeParams {
-    ValidClientArea {
-        data: Option<NonNull<NCCALCSIZE_PARAMS>>
-    } = TRUE as _,
-    Rect {
-        data: Option<NonNull<RECT>>
-    } = FALSE as _,
+/// `WM_UNICHAR` carries UTF-32, so unlike `WM_CHAR` it needs no surrogate pairing.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum UniCharMessage {
+    /// `UNICODE_NOCHAR`, sent to ask whether the window accepts `WM_UNICHAR` at all.
+    Probe,
+    Char(char),
 }
 
+impl UniCharMessage {
+    /// `TRUE` to the probe advertises UTF-32 support; a handled character replies `FALSE`.
+    pub fn into_lresult(self) -> LRESULT {
+        match self {
+            UniCharMessage::Probe => TRUE as LRESULT,
+            UniCharMessage::Char(_) => FALSE as LRESULT,
+        }
+    }
+}
 
-#[cfg(target_pointer_width = "64")]
-const _RAW_SIZE_ASSERT: [u8; 24] = [0; size_of::<RawEvent>()];
-#[cfg(target_pointer_width = "32")]
-const _RAW_SIZE_ASSERT: [u8; 12] = [0; size_of::<RawEvent>()];
-const _WM_SIZE_ASSERT: [u8; 24] = [0; size_of::<WindowMessage>()];
+impl TryFrom<RawEvent> for MouseButtonMessage {
+    type Error = UnexpectedMessage;
+
+    fn try_from(raw: RawEvent) -> Result<Self, Self::Error> {
+        WindowEvent::parse_const(raw.msg, raw.w_param, raw.l_param)
+            .and_then(|message| message.as_mouse_button())
+            .ok_or(UnexpectedMessage(raw))
+    }
+}
+
+impl TryFrom<RawEvent> for KeyMessage {
+    type Error = UnexpectedMessage;
+
+    fn try_from(raw: RawEvent) -> Result<Self, Self::Error> {
+        WindowEvent::parse_const(raw.msg, raw.w_param, raw.l_param)
+            .and_then(|message| message.as_key())
+            .ok_or(UnexpectedMessage(raw))
+    }
+}
+
+/// The raw event is not of the requested kind.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct UnexpectedMessage(pub RawEvent);
+
+impl fmt::Display for UnexpectedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match message_name(self.0.msg) {
+            Some(name) => write!(f, "unexpected message {}", name),
+            None => write!(f, "unexpected message {:#06x}", self.0.msg)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnexpectedMessage {}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct MenuFlags(u16); impl Debug;
+    pub grayed, _: 0;
+    pub disabled, _: 1;
+    pub bitmap, _: 2;
+    pub checked, _: 3;
+    pub popup, _: 4;
+    pub hilite, _: 7;
+    pub owner_draw, _: 8;
+    pub sys_menu, _: 13;
+    pub mouse_select, _: 15;
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MenuItem {
+    Command(WORD),
+    Popup(WORD),
+    Closed
+}
 
 #[derive(Debug, Copy, Clone)]
-pub enum WindowEvent {
-    Message(WindowMessage),
-    User(RawEvent),
-    App(RawEvent),
-    String(RawEvent),
-    Reserved(RawEvent),
+pub struct MenuSelectMessage {
+    pub flags: MenuFlags,
+    pub item: MenuItem,
+    pub menu: HMENU
 }
 
-impl WindowEvent {
-    pub fn parse(msg: UINT, w_param: WPARAM, l_param: LPARAM) -> Self {
-        const WM_USER_1: UINT = WM_USER - 1;
-        const WM_APP_1: UINT = WM_APP - 1;
-        const WM_STRING: UINT = 0xC000;
-        const WM_STRING_1: UINT = WM_STRING - 1;
-        const WM_RESERVED: UINT = 0xFFFF;
-        const WM_RESERVED_1: UINT = WM_RESERVED - 1;
-        match msg {
-            0..=WM_USER_1 => {
-                Self::Message(unsafe {
-                    UWM {
-                        raw: RawEvent { msg, w_param, l_param }
-                    }.typed
-                })
-            },
-            WM_USER..=WM_APP_1 => {
-                Self::User(RawEvent { msg: msg - WM_USER, w_param, l_param })
-            },
-            WM_APP..=WM_STRING_1 => {
-                Self::App(RawEvent { msg: msg - WM_APP, w_param, l_param })
-            },
-            WM_STRING..=WM_RESERVED_1 => {
-                Self::String(RawEvent { msg: msg - WM_APP, w_param, l_param })
-            },
-            WM_RESERVED.. => {
-                Self::Reserved(RawEvent { msg: msg - WM_RESERVED, w_param, l_param })
-            }
+#[derive(Debug, Copy, Clone)]
+pub struct TimerMessage {
+    pub id: usize,
+    /// When set, Windows has already invoked this callback for the tick and the
+    /// window procedure usually has nothing left to do.
+    pub callback: TIMERPROC
+}
+
+#[cfg(feature = "std")]
+pub struct PaintContext {
+    window: HWND,
+    paint: PAINTSTRUCT
+}
+
+#[cfg(feature = "std")]
+impl PaintContext {
+    pub fn hdc(&self) -> HDC {
+        self.paint.hdc
+    }
+
+    pub fn update_rect(&self) -> RECT {
+        self.paint.rcPaint
+    }
+
+    pub fn erase(&self) -> bool {
+        self.paint.fErase != FALSE
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for PaintContext {
+    fn drop(&mut self) {
+        unsafe {
+            EndPaint(self.window, &self.paint);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RawInputCode {
+    Foreground,
+    Sink
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct RawInputMouse {
+    pub device: HANDLE,
+    pub absolute: bool,
+    pub virtual_desktop: bool,
+    pub x: i32,
+    pub y: i32,
+    pub button_flags: u16,
+    pub wheel_delta: i16,
+    pub raw_buttons: u32
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct RawInputKeyboard {
+    pub device: HANDLE,
+    pub make_code: u16,
+    pub flags: u16,
+    pub vkey: u16,
+    pub message: UINT
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum RawInput {
+    Mouse(RawInputMouse),
+    Keyboard(RawInputKeyboard),
+    Hid {
+        device: HANDLE
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct InputDeviceChangeMessage {
+    pub arrived: bool,
+    pub device: HANDLE
+}
+
+#[cfg(feature = "std")]
+impl InputDeviceChangeMessage {
+    pub fn device_name(&self) -> Option<String> {
+        let mut len: UINT = 0;
+        unsafe {
+            GetRawInputDeviceInfoW(self.device, RIDI_DEVICENAME, null_mut(), &mut len);
+        }
+        if len == 0 {
+            return None;
+        }
+        let mut name = vec![0u16; len as usize];
+        let read = unsafe {
+            GetRawInputDeviceInfoW(self.device, RIDI_DEVICENAME, name.as_mut_ptr() as _, &mut len)
+        };
+        if read == UINT::MAX {
+            return None;
+        }
+        let end = name.iter().position(|&c| c == 0).unwrap_or(name.len());
+        Some(String::from_utf16_lossy(&name[..end]))
+    }
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct TouchFlags(u32); impl Debug;
+    pub moved, _: 0;
+    pub down, _: 1;
+    pub up, _: 2;
+    pub in_range, _: 3;
+    pub primary, _: 4;
+    pub no_coalesce, _: 5;
+    pub pen, _: 6;
+    pub palm, _: 7;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct TouchPoint {
+    pub id: u32,
+    /// Hundredths of a physical screen pixel.
+    pub x: i32,
+    pub y: i32,
+    pub flags: TouchFlags
+}
+
+#[cfg(feature = "std")]
+pub struct TouchPoints {
+    handle: HTOUCHINPUT,
+    points: Vec<TOUCHINPUT>,
+    next: usize
+}
+
+#[cfg(feature = "std")]
+impl Iterator for TouchPoints {
+    type Item = TouchPoint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.get(self.next)?;
+        self.next += 1;
+        Some(TouchPoint {
+            id: point.dwID,
+            x: point.x,
+            y: point.y,
+            flags: TouchFlags(point.dwFlags),
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for TouchPoints {
+    fn drop(&mut self) {
+        unsafe {
+            CloseTouchInputHandle(self.handle);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub struct DroppedFiles {
+    handle: HDROP,
+    count: UINT,
+    next: UINT
+}
+
+#[cfg(feature = "std")]
+impl Iterator for DroppedFiles {
+    type Item = std::path::PathBuf;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use std::os::windows::ffi::OsStringExt;
+        if self.next >= self.count {
+            return None;
+        }
+        let index = self.next;
+        self.next += 1;
+        let len = unsafe { DragQueryFileW(self.handle, index, null_mut(), 0) };
+        let mut path = vec![0u16; len as usize + 1];
+        let read = unsafe { DragQueryFileW(self.handle, index, path.as_mut_ptr(), path.len() as UINT) };
+        Some(std::ffi::OsString::from_wide(&path[..read as usize]).into())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for DroppedFiles {
+    fn drop(&mut self) {
+        unsafe {
+            DragFinish(self.handle);
         }
     }
 }
 
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct PointerFlags(u16); impl Debug;
+    pub new, _: 0;
+    pub in_range, _: 1;
+    pub in_contact, _: 2;
+    pub first_button, _: 4;
+    pub second_button, _: 5;
+    pub third_button, _: 6;
+    pub fourth_button, _: 7;
+    pub fifth_button, _: 8;
+    pub primary, _: 13;
+    pub confidence, _: 14;
+    pub canceled, _: 15;
+}
+
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PointerType {
+    Pointer = PT_POINTER,
+    Touch = PT_TOUCH,
+    Pen = PT_PEN,
+    Mouse = PT_MOUSE,
+    TouchPad = PT_TOUCHPAD,
+}
+
 #[repr(u32)]
 #[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PointerButtonChange {
+    None = POINTER_CHANGE_NONE,
+    FirstButtonDown = POINTER_CHANGE_FIRSTBUTTON_DOWN,
+    FirstButtonUp = POINTER_CHANGE_FIRSTBUTTON_UP,
+    SecondButtonDown = POINTER_CHANGE_SECONDBUTTON_DOWN,
+    SecondButtonUp = POINTER_CHANGE_SECONDBUTTON_UP,
+    ThirdButtonDown = POINTER_CHANGE_THIRDBUTTON_DOWN,
+    ThirdButtonUp = POINTER_CHANGE_THIRDBUTTON_UP,
+    FourthButtonDown = POINTER_CHANGE_FOURTHBUTTON_DOWN,
+    FourthButtonUp = POINTER_CHANGE_FOURTHBUTTON_UP,
+    FifthButtonDown = POINTER_CHANGE_FIFTHBUTTON_DOWN,
+    FifthButtonUp = POINTER_CHANGE_FIFTHBUTTON_UP,
+}
+
 #[derive(Debug, Copy, Clone)]
-pub enum WindowMessage {
-    Null = WM_NULL,
-    Create {
-        _unused: WPARAM,
-        data: Option<NonNull<CREATESTRUCTA>> //CREATESTRUCTW?
-    } = WM_CREATE,
-    Destroy = WM_DESTROY,
-    Move {
-        _unused: WPARAM,
-        x: i16,
-        y: i16,
-        _unused2: u32
-    } = WM_MOVE,
-    Size {
-        resizing: WindowResizing,
-        width: i16,
-        height: i16,
-        _unused: u32
-    } = WM_SIZE,
-    Activate {
-        activated: u16,
-        state: WindowActivation,
-        window: HWND
-    } = WM_ACTIVATE,
-    SetFocus {
-        window: Option<NonNull<HWND>>
-    } = WM_SETFOCUS,
-    KillFocus = WM_KILLFOCUS,
-    Enable = WM_ENABLE,
-    SetRedraw = WM_SETREDRAW,
-    SetText = WM_SETTEXT,
-    GetText = WM_GETTEXT,
-    GetTextLength = WM_GETTEXTLENGTH,
-    Paint = WM_PAINT,
-    Close = WM_CLOSE,
-    QueryEndSession = WM_QUERYENDSESSION,
-    QueryOpen = WM_QUERYOPEN,
-    EndSession = WM_ENDSESSION,
-    Quit = WM_QUIT,
-    EraseBackground {
-        dc: HDC,
-        _unused: LPARAM
-    } = WM_ERASEBKGND,
-    SysColorChange = WM_SYSCOLORCHANGE,
-    ShowWindow {
-        shown: WPARAM,
-        status: LPARAM
-    } = WM_SHOWWINDOW,
-    SettingChange = WM_SETTINGCHANGE,
-    DevModeChange = WM_DEVMODECHANGE,
-    ActivateApp {
-        activated: WPARAM,
-        thread: LPARAM
-    } = WM_ACTIVATEAPP,
-    FontChange = WM_FONTCHANGE,
-    TimeChange = WM_TIMECHANGE,
-    CancelMode = WM_CANCELMODE,
-    SetCursor {
-        window: HWND,
-        hit_test: WORD,
-        trigger_message: WORD,
-        _unused: u32
-    } = WM_SETCURSOR,
-    MouseActivate {
-        top_window: HWND,
-        activation: LPARAM
-    } = WM_MOUSEACTIVATE,
-    ChildActivate = WM_CHILDACTIVATE,
-    QueueSync = WM_QUEUESYNC,
-    GetMinMaxInfo {
-        _unused: WPARAM,
-        data: Option<NonNull<MINMAXINFO>>
-    } = WM_GETMINMAXINFO,
-    PaintIcon = WM_PAINTICON,
-    IconEraseBackground = WM_ICONERASEBKGND,
-    NextDialogCtl = WM_NEXTDLGCTL,
-    SpoolerStatus = WM_SPOOLERSTATUS,
-    DrawItem = WM_DRAWITEM,
-    MeasureItem = WM_MEASUREITEM,
-    DeleteItem = WM_DELETEITEM,
-    VKeyToItem = WM_VKEYTOITEM,
-    CharToItem = WM_CHARTOITEM,
-    SetFont = WM_SETFONT,
-    GetFont = WM_GETFONT,
-    SetHotkey = WM_SETHOTKEY,
-    GetHotkey = WM_GETHOTKEY,
-    QueryDragIcon = WM_QUERYDRAGICON,
-    CompareItem = WM_COMPAREITEM,
-    GetObject = WM_GETOBJECT,
-    Compacting = WM_COMPACTING,
-    CommNotify = WM_COMMNOTIFY,
-    WindowPosChanging {
-        _unused: WPARAM,
-        data: Option<NonNull<WINDOWPOS>>
-    } = WM_WINDOWPOSCHANGING,
-    WindowPosChanged {
-        _unused: WPARAM,
-        data: Option<NonNull<WINDOWPOS>>
-    } = WM_WINDOWPOSCHANGED,
-    Power = WM_POWER,
-    CopyData = WM_COPYDATA,
-    CancelJournal = WM_CANCELJOURNAL,
-    Notify = WM_NOTIFY,
-    InputLangChangeRequest = WM_INPUTLANGCHANGEREQUEST,
-    InputLangChange = WM_INPUTLANGCHANGE,
-    TCard = WM_TCARD,
-    Help = WM_HELP,
-    UserChanged = WM_USERCHANGED,
-    NotifyFormat = WM_NOTIFYFORMAT,
-    ContextMenu = WM_CONTEXTMENU,
-    StyleChanging {
-        style: GwlStyle,
-        _unused: u32,
-        data: Option<NonNull<STYLESTRUCT>>
-    } = WM_STYLECHANGING,
-    StyleChanged {
-        style: GwlStyle,
-        _unused: u32,
-        data: Option<NonNull<STYLESTRUCT>>
-    } = WM_STYLECHANGED,
-    DisplayChange = WM_DISPLAYCHANGE,
-    GetIcon {
-        size: IconSize,
-        dpi: LPARAM
-    } = WM_GETICON,
-    SetIcon {
-        size: IconSize,
-        icon: HICON
-    } = WM_SETICON,
-    NcCreate = WM_NCCREATE,
-    NcDestroy = WM_NCDESTROY,
-    NcCalcSize {
-        params: NcSizeParams,
-    } = WM_NCCALCSIZE,
-    NcHitTest {
-        _unused: WPARAM,
-        pos: MousePos
-    } = WM_NCHITTEST,
-    NcPaint {
-        update_region: WPARAM,
-        _unused: LPARAM
-    } = WM_NCPAINT,
-    NcActivate {
-        update_icon: WPARAM,
-        update_region: LPARAM
-    } = WM_NCACTIVATE,
-    GetDlgCode = WM_GETDLGCODE,
-    SyncPaint = WM_SYNCPAINT,
-    NcMouseMove {
-        hit_test: WPARAM,
-        pos: MousePos
-    } = WM_NCMOUSEMOVE,
-    NclButtonDown = WM_NCLBUTTONDOWN,
-    NclButtonUp = WM_NCLBUTTONUP,
-    NclButtonDblClk = WM_NCLBUTTONDBLCLK,
-    NcRButtonDown = WM_NCRBUTTONDOWN,
-    NcRButtonUp = WM_NCRBUTTONUP,
-    NcRButtonDblClk = WM_NCRBUTTONDBLCLK,
-    NcMButtonDown = WM_NCMBUTTONDOWN,
-    NcMButtonUp = WM_NCMBUTTONUP,
-    NcMButtonDblClk = WM_NCMBUTTONDBLCLK,
-    NcXButtonDown = WM_NCXBUTTONDOWN,
-    NcXButtonUp = WM_NCXBUTTONUP,
-    NcXButtonDblClk = WM_NCXBUTTONDBLCLK,
-    InputDeviceChange = WM_INPUT_DEVICE_CHANGE,
-    Input = WM_INPUT,
-    KeyDown {
-        key_code: WPARAM,
-        info: KeyInfo
-    } = WM_KEYDOWN,
-    KeyUp {
-        key_code: WPARAM,
-        info: KeyInfo
-    } = WM_KEYUP,
-    Char = WM_CHAR,
-    DeadChar = WM_DEADCHAR,
-    SysKeyDown {
-        key_code: WPARAM,
-        info: KeyInfo
-    } = WM_SYSKEYDOWN,
-    SysKeyUp {
-        key_code: WPARAM,
-        info: KeyInfo
-    } = WM_SYSKEYUP,
-    SysChar = WM_SYSCHAR,
-    SysDeadChar = WM_SYSDEADCHAR,
-    UniChar = WM_UNICHAR,
-    ImeStartComposition = WM_IME_STARTCOMPOSITION,
-    ImeEndComposition = WM_IME_ENDCOMPOSITION,
-    ImeComposition = WM_IME_COMPOSITION,
-    InitDialog = WM_INITDIALOG,
-    Command = WM_COMMAND,
-    SysCommand = WM_SYSCOMMAND,
-    TIMER = WM_TIMER,
-    HScroll = WM_HSCROLL,
-    VScroll = WM_VSCROLL,
-    InitMenu = WM_INITMENU,
-    InitMenuPopup = WM_INITMENUPOPUP,
-    Gesture = WM_GESTURE,
-    GestureNotify = WM_GESTURENOTIFY,
-    MenuSelect = WM_MENUSELECT,
-    MenuChar = WM_MENUCHAR,
-    EnterIdle = WM_ENTERIDLE,
-    MenuRButtonUp = WM_MENURBUTTONUP,
-    MenuDrag = WM_MENUDRAG,
-    MenuGetObject = WM_MENUGETOBJECT,
-    UninitMenuPopup = WM_UNINITMENUPOPUP,
-    MenuCommand = WM_MENUCOMMAND,
-    ChangeUiState = WM_CHANGEUISTATE,
-    UpdateUiState = WM_UPDATEUISTATE,
-    QueryUiState = WM_QUERYUISTATE,
-    CtlColorMsgBox = WM_CTLCOLORMSGBOX,
-    CtlColorEdit = WM_CTLCOLOREDIT,
-    CtlColorListBox = WM_CTLCOLORLISTBOX,
-    CtlColorBtn = WM_CTLCOLORBTN,
-    CtlColorDlg = WM_CTLCOLORDLG,
-    CtlColorScrollbar = WM_CTLCOLORSCROLLBAR,
-    CtlColorStatic = WM_CTLCOLORSTATIC,
-    MouseMove {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_MOUSEMOVE,
-    LButtonDown {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_LBUTTONDOWN,
-    LButtonUp {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_LBUTTONUP,
-    LButtonDblClk {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_LBUTTONDBLCLK,
-    RButtonDown {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_RBUTTONDOWN,
-    RButtonUp {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_RBUTTONUP,
-    RButtonDblClk {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_RBUTTONDBLCLK,
-    MButtonDown {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_MBUTTONDOWN,
-    MButtonUp {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_MBUTTONUP,
-    MButtonDblClk {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_MBUTTONDBLCLK,
-    MouseWheel {
-        modifiers: WORD,
-        delta: WORD,
-        pos: MousePos
-    } = WM_MOUSEWHEEL,
-    XButtonDown {
-        modifiers: WORD,
-        button: WORD,
-        pos: MousePos
-    } = WM_XBUTTONDOWN,
-    XButtonUp {
-        modifiers: WORD,
-        button: WORD,
-        pos: MousePos
-    } = WM_XBUTTONUP,
-    XButtonDblClk {
-        modifiers: WORD,
-        button: WORD,
-        pos: MousePos
-    } = WM_XBUTTONDBLCLK,
-    NcUahDrawCaption {
-        w: WPARAM,
-        l: LPARAM,
-    } = 0x00AE /*WM_NCUAHDRAWCAPTION*/,
-    NcUahDrawFrame {
-        w: WPARAM,
-        l: LPARAM,
-    } = 0x00AF /*WM_NCUAHDRAWCAPTION */,
-    MouseHWheel {
-        modifiers: WORD,
-        delta: WORD,
-        pos: MousePos
-    } = WM_MOUSEHWHEEL,
-    ParentNotify = WM_PARENTNOTIFY,
-    EnterMenuLoop = WM_ENTERMENULOOP,
-    ExitMenuLoop = WM_EXITMENULOOP,
-    NextMenu = WM_NEXTMENU,
-    Sizing = WM_SIZING,
-    CaptureChanged {
-        _unused: WPARAM,
-        window: HWND
-    } = WM_CAPTURECHANGED,
-    Moving = WM_MOVING,
-    PowerBroadcast {
-        event: PowerEvent,
-        data: Option<NonNull<POWERBROADCAST_SETTING>>
-    } = WM_POWERBROADCAST,
-    DeviceChange = WM_DEVICECHANGE,
-    MdiCreate = WM_MDICREATE,
-    MdiDestroy = WM_MDIDESTROY,
-    MdiActivate = WM_MDIACTIVATE,
-    MdiRestore = WM_MDIRESTORE,
-    MdiNext = WM_MDINEXT,
-    MdiMaximize = WM_MDIMAXIMIZE,
-    MdiTile = WM_MDITILE,
-    MdiCascade = WM_MDICASCADE,
-    MdiIconArrange = WM_MDIICONARRANGE,
-    MdiGetActive = WM_MDIGETACTIVE,
-    MdiSetMenu = WM_MDISETMENU,
-    EnterSizeMove = WM_ENTERSIZEMOVE,
-    ExitSizeMove = WM_EXITSIZEMOVE,
-    DropFiles = WM_DROPFILES,
-    MdiRefreshMenu = WM_MDIREFRESHMENU,
-    PointerDeviceChange = WM_POINTERDEVICECHANGE,
-    PointerDeviceInRange = WM_POINTERDEVICEINRANGE,
-    PointerDeviceOutOfRange = WM_POINTERDEVICEOUTOFRANGE,
-    Touch = WM_TOUCH,
-    NcPointerUpdate = WM_NCPOINTERUPDATE,
-    NcPointerDown = WM_NCPOINTERDOWN,
-    NcPointerUp = WM_NCPOINTERUP,
-    PointerUpdate = WM_POINTERUPDATE,
-    POINTERDOWN = WM_POINTERDOWN,
-    POINTERUP = WM_POINTERUP,
-    POINTERENTER = WM_POINTERENTER,
-    POINTERLEAVE = WM_POINTERLEAVE,
-    PointerActivate = WM_POINTERACTIVATE,
-    PointerCaptureChanged = WM_POINTERCAPTURECHANGED,
-    TouchHitTesting = WM_TOUCHHITTESTING,
-    PointerWheel = WM_POINTERWHEEL,
-    PointerHWheel = WM_POINTERHWHEEL,
-    PointerRoutedTo = WM_POINTERROUTEDTO,
-    PointerRoutedAway = WM_POINTERROUTEDAWAY,
-    PointerRoutedReleased = WM_POINTERROUTEDRELEASED,
-    ImeSetContext {
-        active: WPARAM,
-        display_options: LPARAM
-    } = WM_IME_SETCONTEXT,
-    ImeNotify {
-        window: HWND,
-        command: LPARAM
-    } = WM_IME_NOTIFY,
-    ImeControl = WM_IME_CONTROL,
-    ImeCompositionFull = WM_IME_COMPOSITIONFULL,
-    ImeSelect = WM_IME_SELECT,
-    ImeChar = WM_IME_CHAR,
-    ImeRequest = WM_IME_REQUEST,
-    ImeKeydown = WM_IME_KEYDOWN,
-    ImeKeyup = WM_IME_KEYUP,
-    MouseHover {
-        modifiers: WPARAM,
-        pos: MousePos
-    } = WM_MOUSEHOVER,
-    MouseLeave = WM_MOUSELEAVE,
-    NcMouseHover = WM_NCMOUSEHOVER,
-    NcMouseLeave = WM_NCMOUSELEAVE,
-    WtsSessionChange = WM_WTSSESSION_CHANGE,
-    TabletFirst = WM_TABLET_FIRST,
-    TabletLast = WM_TABLET_LAST,
-    DpiChanged = WM_DPICHANGED,
-    DpiChangedBeforeParent = WM_DPICHANGED_BEFOREPARENT,
-    DpiChangedAfterParent = WM_DPICHANGED_AFTERPARENT,
-    GetDpiScaledSize = WM_GETDPISCALEDSIZE,
-    Cut = WM_CUT,
-    Copy = WM_COPY,
-    Paste = WM_PASTE,
-    Clear = WM_CLEAR,
-    Undo = WM_UNDO,
-    RenderFormat = WM_RENDERFORMAT,
-    RenderAllFormats = WM_RENDERALLFORMATS,
-    DestroyClipboard = WM_DESTROYCLIPBOARD,
-    DrawClipboard = WM_DRAWCLIPBOARD,
-    PaintClipboard = WM_PAINTCLIPBOARD,
-    VScrollClipboard = WM_VSCROLLCLIPBOARD,
-    SizeClipboard = WM_SIZECLIPBOARD,
-    AskCbFormatName = WM_ASKCBFORMATNAME,
-    ChangeCbChain = WM_CHANGECBCHAIN,
-    HScrollClipboard = WM_HSCROLLCLIPBOARD,
-    QueryNewPalette = WM_QUERYNEWPALETTE,
-    PaletteIsChanging = WM_PALETTEISCHANGING,
-    PaletteChanged = WM_PALETTECHANGED,
-    Hotkey = WM_HOTKEY,
-    Print = WM_PRINT,
-    PrintClient = WM_PRINTCLIENT,
-    AppCommand = WM_APPCOMMAND,
-    ThemeChanged = WM_THEMECHANGED,
-    ClipboardUpdate = WM_CLIPBOARDUPDATE,
-    DwmCompositionChanged = WM_DWMCOMPOSITIONCHANGED,
-    DwmNcRenderingChanged = WM_DWMNCRENDERINGCHANGED,
-    DwmColorizationColorChanged = WM_DWMCOLORIZATIONCOLORCHANGED,
-    DwmWindowMaximizedChange = WM_DWMWINDOWMAXIMIZEDCHANGE,
-    DwmSendIconIcThumbnail = WM_DWMSENDICONICTHUMBNAIL,
-    DwmSendIconIcLivePreviewBitmap = WM_DWMSENDICONICLIVEPREVIEWBITMAP,
-    GetTitleBarInfoEx = WM_GETTITLEBARINFOEX,
-    HandHeldFirst = WM_HANDHELDFIRST,
-    HandHeldLast = WM_HANDHELDLAST,
-    AfxFirst = WM_AFXFIRST,
-    AfxLast = WM_AFXLAST,
-    PenWinFirst = WM_PENWINFIRST,
-    PenWinLast = WM_PENWINLAST
+pub struct PointerInfo {
+    pub pointer_type: Option<PointerType>,
+    pub id: u32,
+    pub frame_id: u32,
+    pub flags: u32,
+    pub source: HANDLE,
+    pub target: HWND,
+    pub pixel_location: (i32, i32),
+    pub himetric_location: (i32, i32),
+    pub time: DWORD,
+    pub button_change: Option<PointerButtonChange>
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct GestureFlags(u32); impl Debug;
+    pub begin, _: 0;
+    pub inertia, _: 1;
+    pub end, _: 2;
+}
+
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GestureKind {
+    Begin,
+    End,
+    Zoom {
+        distance: u32
+    },
+    Pan {
+        distance: u32
+    },
+    Rotate {
+        angle: f64
+    },
+    TwoFingerTap {
+        distance: u32
+    },
+    PressAndTap {
+        dx: i16,
+        dy: i16
+    },
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct GestureInfo {
+    pub kind: GestureKind,
+    pub flags: GestureFlags,
+    pub target: HWND,
+    pub location: MousePos,
+    pub instance_id: u32,
+    pub sequence_id: u32,
+    handle: HGESTUREINFO
+}
+
+#[cfg(feature = "std")]
+impl Drop for GestureInfo {
+    fn drop(&mut self) {
+        unsafe {
+            CloseGestureInfoHandle(self.handle);
+        }
+    }
+}
+
+bitfield! {
+    #[derive(Copy, Clone, Default)]
+    pub struct Gestures(u32); impl Debug;
+    pub zoom, set_zoom: 0;
+    pub pan, set_pan: 1;
+    pub rotate, set_rotate: 2;
+    pub two_finger_tap, set_two_finger_tap: 3;
+    pub press_and_tap, set_press_and_tap: 4;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GestureNotifyMessage {
+    pub target: HWND,
+    pub location: MousePos,
+    pub instance_id: u32
+}
+
+impl GestureNotifyMessage {
+    #[cfg(feature = "std")]
+    /// Enables the chosen gestures on `target` and blocks the rest; must be called before
+    /// the message returns to take effect for the gesture about to start.
+    pub fn configure(&self, gestures: Gestures) -> bool {
+        let config = |id, flag, wanted: bool| GESTURECONFIG {
+            dwID: id,
+            dwWant: if wanted { flag } else { 0 },
+            dwBlock: if wanted { 0 } else { flag },
+        };
+        let configs = [
+            config(GID_ZOOM, GC_ZOOM, gestures.zoom()),
+            config(GID_PAN, GC_PAN, gestures.pan()),
+            config(GID_ROTATE, GC_ROTATE, gestures.rotate()),
+            config(GID_TWOFINGERTAP, GC_TWOFINGERTAP, gestures.two_finger_tap()),
+            config(GID_PRESSANDTAP, GC_PRESSANDTAP, gestures.press_and_tap()),
+        ];
+        unsafe {
+            SetGestureConfig(
+                self.target,
+                0,
+                configs.len() as UINT,
+                configs.as_ptr(),
+                size_of::<GESTURECONFIG>() as UINT
+            ) != FALSE
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct MdiActivateMessage {
+    pub deactivated: HWND,
+    pub activated: HWND
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ActivateAppMessage {
+    pub activated: bool,
+    pub thread_id: u32
+}
+
+bitfield! {
+    /// The `CN_RECEIVE`, `CN_TRANSMIT` and `CN_EVENT` notifications.
+    #[derive(Copy, Clone)]
+    pub struct CommEvents(u16); impl Debug;
+    pub receive, _: 0;
+    pub transmit, _: 1;
+    pub event, _: 2;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct CommNotifyMessage {
+    pub device_id: u16,
+    pub events: CommEvents
+}
+
+bitfield! {
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Copy, Clone, Eq, PartialEq)]
+    pub struct Argb(u32); impl Debug;
+    u8;
+    pub alpha, _: 31, 24;
+    pub red, _: 23, 16;
+    pub green, _: 15, 8;
+    pub blue, _: 7, 0;
+}
+
+impl From<Argb> for u32 {
+    fn from(color: Argb) -> Self {
+        color.0
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ColorizationMessage {
+    pub color: Argb,
+    pub opaque_blend: bool
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SpoolerStatusMessage {
+    /// Always `PR_JOBSTATUS`.
+    pub status: u32,
+    pub jobs_remaining: u16
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct EndSessionReason(u32); impl Debug;
+    pub close_app, _: 0;
+    pub critical, _: 30;
+    pub logoff, _: 31;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct EndSessionMessage {
+    pub ending: bool,
+    pub reason: EndSessionReason
+}
+
+/// The region belongs to the system; the handler must not delete it.
+#[derive(Debug, Copy, Clone)]
+pub enum NcUpdateRegion {
+    EntireFrame,
+    Region(HRGN),
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum NcActivateRegion {
+    Entire,
+    Region(HRGN),
+    Suppress,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct NcActivateMessage {
+    pub active: bool,
+    pub update_region: NcActivateRegion
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct DlgCode(u32); impl Debug;
+    pub want_arrows, set_want_arrows: 0;
+    pub want_tab, set_want_tab: 1;
+    pub want_all_keys, set_want_all_keys: 2;
+    pub has_set_sel, set_has_set_sel: 3;
+    pub def_push_button, set_def_push_button: 4;
+    pub undef_push_button, set_undef_push_button: 5;
+    pub radio_button, set_radio_button: 6;
+    pub want_chars, set_want_chars: 7;
+    pub static_control, set_static_control: 8;
+    pub button, set_button: 13;
+}
+
+impl DlgCode {
+    pub fn into_lresult(self) -> LRESULT {
+        self.0 as LRESULT
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct GetDlgCodeMessage<'a> {
+    pub key: Option<VirtualKey>,
+    pub msg: Option<&'a MSG>
+}
+
+/// Indexes into the `rgrect` and `rgstate` arrays of `TITLEBARINFOEX`.
+#[repr(usize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TitleBarPart {
+    TitleBar = 0,
+    Minimize = 2,
+    Maximize = 3,
+    Help = 4,
+    Close = 5,
+}
+
+bitfield! {
+    /// The `STATE_SYSTEM_*` flags a title bar part can report.
+    #[derive(Copy, Clone, Default)]
+    pub struct TitleBarState(u32); impl Debug;
+    pub unavailable, set_unavailable: 0;
+    pub pressed, set_pressed: 3;
+    pub hot_tracked, set_hot_tracked: 7;
+    pub invisible, set_invisible: 15;
+    pub offscreen, set_offscreen: 16;
+    pub focusable, set_focusable: 20;
 }
 
-impl WindowMessage {
-    pub fn as_key(&self) -> Option<KeyMessage> {
+/// The structure the handler fills in; the rects are in screen coordinates.
+pub struct TitleBarInfo<'a>(pub &'a mut TITLEBARINFOEX);
+
+impl TitleBarInfo<'_> {
+    pub fn rect(&self, part: TitleBarPart) -> RECT {
+        self.0.rgrect[part as usize]
+    }
+
+    pub fn set_rect(&mut self, part: TitleBarPart, rect: RECT) {
+        self.0.rgrect[part as usize] = rect;
+    }
+
+    pub fn state(&self, part: TitleBarPart) -> TitleBarState {
+        TitleBarState(self.0.rgstate[part as usize])
+    }
+
+    pub fn set_state(&mut self, part: TitleBarPart, state: TitleBarState) {
+        self.0.rgstate[part as usize] = state.0;
+    }
+}
+
+#[repr(u16)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum UiStateAction {
+    Set = UIS_SET as _,
+    Clear = UIS_CLEAR as _,
+    Initialize = UIS_INITIALIZE as _,
+}
+
+impl UiStateAction {
+    pub fn from_code(code: u16) -> Option<Self> {
+        [
+            UiStateAction::Set,
+            UiStateAction::Clear,
+            UiStateAction::Initialize
+        ].into_iter().find(|&action| action as u16 == code)
+    }
+}
+
+bitfield! {
+    #[derive(Copy, Clone, Default)]
+    pub struct UiStateFlags(u16); impl Debug;
+    pub hide_focus, set_hide_focus: 0;
+    pub hide_accel, set_hide_accel: 1;
+    pub active, set_active: 2;
+}
+
+impl UiStateFlags {
+    /// The reply to `QueryUiState`.
+    pub fn into_lresult(self) -> LRESULT {
+        self.0 as LRESULT
+    }
+}
+
+/// `flags` picks the states that `action` sets or clears.
+#[derive(Debug, Copy, Clone)]
+pub struct UiStateMessage {
+    pub action: UiStateAction,
+    pub flags: UiStateFlags
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HelpContext {
+    Menu,
+    Window,
+}
+
+/// `item_handle` is the menu or the control, and `pos` the screen position of the mouse
+/// when help was requested.
+#[derive(Debug, Copy, Clone)]
+pub struct HelpInfo {
+    pub context_type: HelpContext,
+    pub ctrl_id: i32,
+    pub item_handle: HANDLE,
+    pub pos: MousePos
+}
+
+bitfield! {
+    /// The `INPUTLANGCHANGE_*` flags of a requested layout change.
+    #[derive(Copy, Clone)]
+    pub struct InputLangChangeFlags(u32); impl Debug;
+    pub sys_charset, _: 0;
+    pub forward, _: 1;
+    pub backward, _: 2;
+}
+
+/// Forwarding to `DefWindowProc` accepts the new layout; replying 0 rejects it.
+#[derive(Debug, Copy, Clone)]
+pub struct InputLangChangeRequestMessage {
+    pub flags: InputLangChangeFlags,
+    pub layout: HKL
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct InputLangChangeMessage {
+    pub charset: u8,
+    pub layout: HKL
+}
+
+/// A button clicked on a training card, or another application asking for the cards.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TrainingCardMessage {
+    Ok,
+    Cancel,
+    Abort,
+    Retry,
+    Ignore,
+    Yes,
+    No,
+    Close,
+    Help,
+    /// An authorable button, with the value the help author gave it.
+    Data(LPARAM),
+    OtherCaller,
+    Custom { id: WPARAM, data: LPARAM },
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct AskCbFormatNameMessage {
+    /// In UTF-16 units, including the terminating null.
+    pub capacity: usize,
+    pub buffer: NonNull<u16>
+}
+
+impl AskCbFormatNameMessage {
+    /// Writes `name` null-terminated, truncated to fit, and returns the units written
+    /// before the null.
+    ///
+    /// # Safety
+    /// `buffer` must be valid for `capacity` writes, which holds while the message is
+    /// being handled.
+    pub unsafe fn write_name(&self, name: &str) -> usize {
+        if self.capacity == 0 {
+            return 0;
+        }
+        let mut written = 0;
+        for unit in name.encode_utf16().take(self.capacity - 1) {
+            self.buffer.add(written).write(unit);
+            written += 1;
+        }
+        self.buffer.add(written).write(0);
+        written
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PaintClipboardMessage {
+    pub viewer: HWND,
+    pub paint: HGLOBAL
+}
+
+impl PaintClipboardMessage {
+    #[cfg(feature = "std")]
+    pub fn lock(&self) -> Option<ClipboardPaint> {
+        let paint = NonNull::new(unsafe { GlobalLock(self.paint) } as *mut PAINTSTRUCT)?;
+        Some(ClipboardPaint { handle: self.paint, paint })
+    }
+}
+
+/// The viewer's `PAINTSTRUCT`, unlocked again when dropped.
+#[cfg(feature = "std")]
+pub struct ClipboardPaint {
+    handle: HGLOBAL,
+    paint: NonNull<PAINTSTRUCT>
+}
+
+#[cfg(feature = "std")]
+impl ClipboardPaint {
+    pub fn paint(&self) -> &PAINTSTRUCT {
+        unsafe { self.paint.as_ref() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for ClipboardPaint {
+    fn drop(&mut self) {
+        unsafe {
+            GlobalUnlock(self.handle);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct CopyDataMessage<'a> {
+    pub sender: HWND,
+    pub tag: usize,
+    pub data: &'a [u8]
+}
+
+impl<'a> CopyDataMessage<'a> {
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn as_str(&self) -> Option<&'a str> {
+        core::str::from_utf8(self.data).ok()
+    }
+}
+
+/// The icon or cursor shown while a minimized window is dragged. `Default` lets the system
+/// use its default cursor. The handle must outlive the drag.
+#[derive(Debug, Copy, Clone)]
+pub enum QueryDragIconReply {
+    Icon(HICON),
+    Default,
+}
+
+impl QueryDragIconReply {
+    pub fn into_lresult(self) -> LRESULT {
+        match self {
+            QueryDragIconReply::Icon(icon) => icon as LRESULT,
+            QueryDragIconReply::Default => 0,
+        }
+    }
+}
+
+/// `font` stays owned by the sender; a null handle means the system font.
+#[derive(Debug, Copy, Clone)]
+pub struct SetFontMessage {
+    pub font: HFONT,
+    pub redraw: bool
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct EraseBackgroundMessage {
+    pub dc: HDC
+}
+
+impl EraseBackgroundMessage {
+    /// The background was erased; `Paint` will see `erase()` as false.
+    pub fn erased() -> LRESULT {
+        1
+    }
+
+    /// The background is left for `Paint` to erase.
+    pub fn not_erased() -> LRESULT {
+        0
+    }
+
+    #[cfg(feature = "std")]
+    /// Fills the client area of `window` with `brush` and returns `erased()`.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn fill(&self, window: HWND, brush: HBRUSH) -> LRESULT {
+        let mut rect: RECT = unsafe { core::mem::zeroed() };
+        unsafe {
+            GetClientRect(window, &mut rect);
+            FillRect(self.dc, &rect, brush);
+        }
+        Self::erased()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum ParentNotifyMessage {
+    ChildCreated { id: u16, window: HWND },
+    ChildDestroyed { id: u16, window: HWND },
+    Mouse { button: MouseButton, pos: MousePos },
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum WheelAxis {
+    Vertical,
+    Horizontal,
+}
+
+/// `pos` is in screen coordinates; `to_client` converts it.
+#[derive(Debug, Copy, Clone)]
+pub struct MouseWheelMessage {
+    pub axis: WheelAxis,
+    pub delta: i16,
+    pub modifiers: Modifiers,
+    pub pos: MousePos,
+    pub space: CoordSpace
+}
+
+impl MouseWheelMessage {
+    #[cfg(feature = "std")]
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn to_client(self, window: HWND) -> Self {
+        MouseWheelMessage { pos: self.pos.into_space(self.space, CoordSpace::Client, window), space: CoordSpace::Client, ..self }
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn to_screen(self, window: HWND) -> Self {
+        MouseWheelMessage { pos: self.pos.into_space(self.space, CoordSpace::Screen, window), space: CoordSpace::Screen, ..self }
+    }
+}
+
+/// Unlike client button messages, `pos` is in screen coordinates.
+#[derive(Debug, Copy, Clone)]
+pub struct NcMouseButtonMessage {
+    pub action: MouseButtonAction,
+    pub button: MouseButton,
+    pub hit_test: Option<HitTest>,
+    pub pos: MousePos,
+    pub space: CoordSpace
+}
+
+impl NcMouseButtonMessage {
+    #[cfg(feature = "std")]
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn to_client(self, window: HWND) -> Self {
+        NcMouseButtonMessage { pos: self.pos.into_space(self.space, CoordSpace::Client, window), space: CoordSpace::Client, ..self }
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn to_screen(self, window: HWND) -> Self {
+        NcMouseButtonMessage { pos: self.pos.into_space(self.space, CoordSpace::Screen, window), space: CoordSpace::Screen, ..self }
+    }
+}
+
+#[repr(u16)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum AppCommand {
+    BrowserBackward = APPCOMMAND_BROWSER_BACKWARD as _,
+    BrowserForward = APPCOMMAND_BROWSER_FORWARD as _,
+    BrowserRefresh = APPCOMMAND_BROWSER_REFRESH as _,
+    BrowserStop = APPCOMMAND_BROWSER_STOP as _,
+    BrowserSearch = APPCOMMAND_BROWSER_SEARCH as _,
+    BrowserFavorites = APPCOMMAND_BROWSER_FAVORITES as _,
+    BrowserHome = APPCOMMAND_BROWSER_HOME as _,
+    VolumeMute = APPCOMMAND_VOLUME_MUTE as _,
+    VolumeDown = APPCOMMAND_VOLUME_DOWN as _,
+    VolumeUp = APPCOMMAND_VOLUME_UP as _,
+    MediaNextTrack = APPCOMMAND_MEDIA_NEXTTRACK as _,
+    MediaPreviousTrack = APPCOMMAND_MEDIA_PREVIOUSTRACK as _,
+    MediaStop = APPCOMMAND_MEDIA_STOP as _,
+    MediaPlayPause = APPCOMMAND_MEDIA_PLAY_PAUSE as _,
+    LaunchMail = APPCOMMAND_LAUNCH_MAIL as _,
+    LaunchMediaSelect = APPCOMMAND_LAUNCH_MEDIA_SELECT as _,
+    LaunchApp1 = APPCOMMAND_LAUNCH_APP1 as _,
+    LaunchApp2 = APPCOMMAND_LAUNCH_APP2 as _,
+    BassDown = APPCOMMAND_BASS_DOWN as _,
+    BassBoost = APPCOMMAND_BASS_BOOST as _,
+    BassUp = APPCOMMAND_BASS_UP as _,
+    TrebleDown = APPCOMMAND_TREBLE_DOWN as _,
+    TrebleUp = APPCOMMAND_TREBLE_UP as _,
+    MicrophoneVolumeMute = APPCOMMAND_MICROPHONE_VOLUME_MUTE as _,
+    MicrophoneVolumeDown = APPCOMMAND_MICROPHONE_VOLUME_DOWN as _,
+    MicrophoneVolumeUp = APPCOMMAND_MICROPHONE_VOLUME_UP as _,
+    Help = APPCOMMAND_HELP as _,
+    Find = APPCOMMAND_FIND as _,
+    New = APPCOMMAND_NEW as _,
+    Open = APPCOMMAND_OPEN as _,
+    Close = APPCOMMAND_CLOSE as _,
+    Save = APPCOMMAND_SAVE as _,
+    Print = APPCOMMAND_PRINT as _,
+    Undo = APPCOMMAND_UNDO as _,
+    Redo = APPCOMMAND_REDO as _,
+    Copy = APPCOMMAND_COPY as _,
+    Cut = APPCOMMAND_CUT as _,
+    Paste = APPCOMMAND_PASTE as _,
+    ReplyToMail = APPCOMMAND_REPLY_TO_MAIL as _,
+    ForwardMail = APPCOMMAND_FORWARD_MAIL as _,
+    SendMail = APPCOMMAND_SEND_MAIL as _,
+    SpellCheck = APPCOMMAND_SPELL_CHECK as _,
+    DictateOrCommandControlToggle = APPCOMMAND_DICTATE_OR_COMMAND_CONTROL_TOGGLE as _,
+    MicOnOffToggle = APPCOMMAND_MIC_ON_OFF_TOGGLE as _,
+    CorrectionList = APPCOMMAND_CORRECTION_LIST as _,
+    MediaPlay = APPCOMMAND_MEDIA_PLAY as _,
+    MediaPause = APPCOMMAND_MEDIA_PAUSE as _,
+    MediaRecord = APPCOMMAND_MEDIA_RECORD as _,
+    MediaFastForward = APPCOMMAND_MEDIA_FAST_FORWARD as _,
+    MediaRewind = APPCOMMAND_MEDIA_REWIND as _,
+    MediaChannelUp = APPCOMMAND_MEDIA_CHANNEL_UP as _,
+    MediaChannelDown = APPCOMMAND_MEDIA_CHANNEL_DOWN as _,
+    Delete = APPCOMMAND_DELETE as _,
+    DwmFlip3D = APPCOMMAND_DWM_FLIP3D as _,
+}
+
+impl AppCommand {
+    pub fn from_code(code: u16) -> Option<Self> {
+        [
+            AppCommand::BrowserBackward,
+            AppCommand::BrowserForward,
+            AppCommand::BrowserRefresh,
+            AppCommand::BrowserStop,
+            AppCommand::BrowserSearch,
+            AppCommand::BrowserFavorites,
+            AppCommand::BrowserHome,
+            AppCommand::VolumeMute,
+            AppCommand::VolumeDown,
+            AppCommand::VolumeUp,
+            AppCommand::MediaNextTrack,
+            AppCommand::MediaPreviousTrack,
+            AppCommand::MediaStop,
+            AppCommand::MediaPlayPause,
+            AppCommand::LaunchMail,
+            AppCommand::LaunchMediaSelect,
+            AppCommand::LaunchApp1,
+            AppCommand::LaunchApp2,
+            AppCommand::BassDown,
+            AppCommand::BassBoost,
+            AppCommand::BassUp,
+            AppCommand::TrebleDown,
+            AppCommand::TrebleUp,
+            AppCommand::MicrophoneVolumeMute,
+            AppCommand::MicrophoneVolumeDown,
+            AppCommand::MicrophoneVolumeUp,
+            AppCommand::Help,
+            AppCommand::Find,
+            AppCommand::New,
+            AppCommand::Open,
+            AppCommand::Close,
+            AppCommand::Save,
+            AppCommand::Print,
+            AppCommand::Undo,
+            AppCommand::Redo,
+            AppCommand::Copy,
+            AppCommand::Cut,
+            AppCommand::Paste,
+            AppCommand::ReplyToMail,
+            AppCommand::ForwardMail,
+            AppCommand::SendMail,
+            AppCommand::SpellCheck,
+            AppCommand::DictateOrCommandControlToggle,
+            AppCommand::MicOnOffToggle,
+            AppCommand::CorrectionList,
+            AppCommand::MediaPlay,
+            AppCommand::MediaPause,
+            AppCommand::MediaRecord,
+            AppCommand::MediaFastForward,
+            AppCommand::MediaRewind,
+            AppCommand::MediaChannelUp,
+            AppCommand::MediaChannelDown,
+            AppCommand::Delete,
+            AppCommand::DwmFlip3D
+        ].into_iter().find(|&command| command as u16 == code)
+    }
+}
+
+/// The transport and volume subset of `AppCommand`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MediaCommand {
+    PlayPause,
+    Play,
+    Pause,
+    Stop,
+    NextTrack,
+    PreviousTrack,
+    Record,
+    FastForward,
+    Rewind,
+    VolumeMute,
+    VolumeDown,
+    VolumeUp,
+}
+
+impl MediaCommand {
+    pub fn from_app_command(command: AppCommand) -> Option<Self> {
+        Some(match command {
+            AppCommand::MediaPlayPause => MediaCommand::PlayPause,
+            AppCommand::MediaPlay => MediaCommand::Play,
+            AppCommand::MediaPause => MediaCommand::Pause,
+            AppCommand::MediaStop => MediaCommand::Stop,
+            AppCommand::MediaNextTrack => MediaCommand::NextTrack,
+            AppCommand::MediaPreviousTrack => MediaCommand::PreviousTrack,
+            AppCommand::MediaRecord => MediaCommand::Record,
+            AppCommand::MediaFastForward => MediaCommand::FastForward,
+            AppCommand::MediaRewind => MediaCommand::Rewind,
+            AppCommand::VolumeMute => MediaCommand::VolumeMute,
+            AppCommand::VolumeDown => MediaCommand::VolumeDown,
+            AppCommand::VolumeUp => MediaCommand::VolumeUp,
+            _ => return None
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AppCommandDevice {
+    Key,
+    Mouse,
+    Oem,
+}
+
+/// `window` is where the command was triggered, which may be a child of the receiver.
+/// Handled commands reply `TRUE`; the rest go to `DefWindowProc`, which bubbles them up.
+#[derive(Debug, Copy, Clone)]
+pub struct AppCommandMessage {
+    pub window: HWND,
+    pub command: Option<AppCommand>,
+    pub device: Option<AppCommandDevice>,
+    pub keys: Modifiers
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetCursorMessage {
+    pub window: HWND,
+    pub hit_test: Option<HitTest>,
+    pub trigger_message: u32
+}
+
+impl SetCursorMessage {
+    pub fn trigger_name(&self) -> Option<&'static str> {
+        message_name(self.trigger_message)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct MouseActivateMessage {
+    pub top_window: HWND,
+    pub hit_test: Option<HitTest>,
+    pub trigger_message: u16
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PointerActivateMessage {
+    pub pointer_id: u16,
+    pub hit_test: Option<HitTest>,
+    pub top_window: HWND
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ObjectId {
+    Window,
+    SysMenu,
+    TitleBar,
+    Menu,
+    Client,
+    VScroll,
+    HScroll,
+    SizeGrip,
+    Caret,
+    Cursor,
+    Alert,
+    Sound,
+    QueryClassNameIdx,
+    NativeOm,
+    Other(i32),
+}
+
+impl ObjectId {
+    pub fn from_code(code: i32) -> ObjectId {
+        match code {
+            OBJID_WINDOW => ObjectId::Window,
+            OBJID_SYSMENU => ObjectId::SysMenu,
+            OBJID_TITLEBAR => ObjectId::TitleBar,
+            OBJID_MENU => ObjectId::Menu,
+            OBJID_CLIENT => ObjectId::Client,
+            OBJID_VSCROLL => ObjectId::VScroll,
+            OBJID_HSCROLL => ObjectId::HScroll,
+            OBJID_SIZEGRIP => ObjectId::SizeGrip,
+            OBJID_CARET => ObjectId::Caret,
+            OBJID_CURSOR => ObjectId::Cursor,
+            OBJID_ALERT => ObjectId::Alert,
+            OBJID_SOUND => ObjectId::Sound,
+            OBJID_QUERYCLASSNAMEIDX => ObjectId::QueryClassNameIdx,
+            OBJID_NATIVEOM => ObjectId::NativeOm,
+            other => ObjectId::Other(other),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetObjectMessage {
+    pub object_id: i32,
+    pub flags: u32
+}
+
+impl GetObjectMessage {
+    pub fn object(&self) -> ObjectId {
+        ObjectId::from_code(self.object_id)
+    }
+
+    #[cfg(feature = "std")]
+    /// Wraps `LresultFromObject`.
+    ///
+    /// # Safety
+    /// `object` must be a live COM interface pointer of the type named by `riid`.
+    pub unsafe fn lresult_from_object(&self, riid: &GUID, object: *mut core::ffi::c_void) -> LRESULT {
+        LresultFromObject(riid, self.flags as WPARAM, object.cast())
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct DpiChangedMessage {
+    pub dpi_x: u16,
+    pub dpi_y: u16,
+    pub suggested_rect: RECT
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum IdleReason {
+    Dialog,
+    Menu
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct EnterIdleMessage {
+    pub reason: IdleReason,
+    pub window: HWND
+}
+
+#[repr(usize)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SessionEvent {
+    ConsoleConnect = WTS_CONSOLE_CONNECT as _,
+    ConsoleDisconnect = WTS_CONSOLE_DISCONNECT as _,
+    RemoteConnect = WTS_REMOTE_CONNECT as _,
+    RemoteDisconnect = WTS_REMOTE_DISCONNECT as _,
+    Logon = WTS_SESSION_LOGON as _,
+    Logoff = WTS_SESSION_LOGOFF as _,
+    Lock = WTS_SESSION_LOCK as _,
+    Unlock = WTS_SESSION_UNLOCK as _,
+    RemoteControl = WTS_SESSION_REMOTE_CONTROL as _,
+    Create = WTS_SESSION_CREATE as _,
+    Terminate = WTS_SESSION_TERMINATE as _,
+}
+
+impl SessionEvent {
+    pub fn from_code(code: WPARAM) -> Option<Self> {
+        [
+            SessionEvent::ConsoleConnect,
+            SessionEvent::ConsoleDisconnect,
+            SessionEvent::RemoteConnect,
+            SessionEvent::RemoteDisconnect,
+            SessionEvent::Logon,
+            SessionEvent::Logoff,
+            SessionEvent::Lock,
+            SessionEvent::Unlock,
+            SessionEvent::RemoteControl,
+            SessionEvent::Create,
+            SessionEvent::Terminate
+        ].into_iter().find(|&event| event as WPARAM == code)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SessionChangeMessage {
+    pub event: SessionEvent,
+    pub session_id: u32
+}
+
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ImeNotifyCommand {
+    CloseStatusWindow = IMN_CLOSESTATUSWINDOW,
+    OpenStatusWindow = IMN_OPENSTATUSWINDOW,
+    ChangeCandidate = IMN_CHANGECANDIDATE,
+    CloseCandidate = IMN_CLOSECANDIDATE,
+    OpenCandidate = IMN_OPENCANDIDATE,
+    SetConversionMode = IMN_SETCONVERSIONMODE,
+    SetSentenceMode = IMN_SETSENTENCEMODE,
+    SetOpenStatus = IMN_SETOPENSTATUS,
+    SetCandidatePos = IMN_SETCANDIDATEPOS,
+    SetCompositionFont = IMN_SETCOMPOSITIONFONT,
+    SetCompositionWindow = IMN_SETCOMPOSITIONWINDOW,
+    SetStatusWindowPos = IMN_SETSTATUSWINDOWPOS,
+    Guideline = IMN_GUIDELINE,
+    Private = IMN_PRIVATE,
+    SoftKbdDestroyed = IMN_SOFTKBDDESTROYED,
+}
+
+impl ImeNotifyCommand {
+    pub fn from_code(code: u32) -> Option<Self> {
+        [
+            ImeNotifyCommand::CloseStatusWindow,
+            ImeNotifyCommand::OpenStatusWindow,
+            ImeNotifyCommand::ChangeCandidate,
+            ImeNotifyCommand::CloseCandidate,
+            ImeNotifyCommand::OpenCandidate,
+            ImeNotifyCommand::SetConversionMode,
+            ImeNotifyCommand::SetSentenceMode,
+            ImeNotifyCommand::SetOpenStatus,
+            ImeNotifyCommand::SetCandidatePos,
+            ImeNotifyCommand::SetCompositionFont,
+            ImeNotifyCommand::SetCompositionWindow,
+            ImeNotifyCommand::SetStatusWindowPos,
+            ImeNotifyCommand::Guideline,
+            ImeNotifyCommand::Private,
+            ImeNotifyCommand::SoftKbdDestroyed
+        ].into_iter().find(|&command| command as u32 == code)
+    }
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct ImeDisplayOptions(u32); impl Debug;
+    pub u8, candidate_windows, _: 3, 0;
+    pub show_guideline, _: 30;
+    pub show_composition_window, _: 31;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ImeSetContextMessage {
+    pub active: bool,
+    pub display_options: ImeDisplayOptions
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct VKeyToItemMessage {
+    /// `None` for codes `VirtualKey` has no variant for; `key_code` has them all.
+    pub key: Option<VirtualKey>,
+    pub key_code: u16,
+    pub caret: u16,
+    pub list: HWND
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct CharToItemMessage {
+    pub character: u16,
+    pub caret: u16,
+    pub list: HWND
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ItemResult {
+    Default,
+    NoChange,
+    Select(u16),
+}
+
+impl ItemResult {
+    pub fn into_lresult(self) -> LRESULT {
+        match self {
+            ItemResult::Default => -1,
+            ItemResult::NoChange => -2,
+            ItemResult::Select(index) => index as LRESULT,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NextDlgCtl {
+    Focus(HWND),
+    /// Move along the tab order, backwards when `previous`.
+    Direction {
+        previous: bool
+    },
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct MenuDragMessage {
+    pub position: u32,
+    pub menu: HMENU
+}
+
+/// The `SC_*` command of a `SysCommand`, with the bits the system uses internally masked off.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SystemCommand {
+    Size,
+    Move,
+    Minimize,
+    Maximize,
+    NextWindow,
+    PrevWindow,
+    Close,
+    VScroll,
+    HScroll,
+    MouseMenu,
+    /// The window menu opened with Alt and `accel_char`, or with Alt alone for `None`.
+    KeyMenu { accel_char: Option<char> },
+    Arrange,
+    Restore,
+    TaskList,
+    ScreenSave,
+    Hotkey,
+    Default,
+    MonitorPower,
+    ContextHelp,
+    /// An item the application added to the window menu.
+    Other(WPARAM),
+}
+
+impl SystemCommand {
+    /// The character pressed with Alt to open the window menu; `None` for other commands.
+    pub fn accel_char(self) -> Option<char> {
+        match self {
+            SystemCommand::KeyMenu { accel_char } => accel_char,
+            _ => None
+        }
+    }
+}
+
+/// `pos` is the screen position of the mouse when the command was chosen with it.
+#[derive(Debug, Copy, Clone)]
+pub struct SysCommandMessage {
+    pub command: SystemCommand,
+    pub pos: MousePos
+}
+
+/// Sent instead of `WM_COMMAND` by menus with `MNS_NOTIFYBYPOS`.
+#[derive(Debug, Copy, Clone)]
+pub struct MenuCommandMessage {
+    pub index: u32,
+    pub menu: HMENU
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MenuDragResult {
+    Continue,
+    EndMenu,
+}
+
+impl MenuDragResult {
+    pub fn into_lresult(self) -> LRESULT {
+        match self {
+            MenuDragResult::Continue => MND_CONTINUE as LRESULT,
+            MenuDragResult::EndMenu => MND_ENDMENU as LRESULT,
+        }
+    }
+}
+
+#[repr(usize)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SizingEdge {
+    Left = WMSZ_LEFT as _,
+    Right = WMSZ_RIGHT as _,
+    Top = WMSZ_TOP as _,
+    TopLeft = WMSZ_TOPLEFT as _,
+    TopRight = WMSZ_TOPRIGHT as _,
+    Bottom = WMSZ_BOTTOM as _,
+    BottomLeft = WMSZ_BOTTOMLEFT as _,
+    BottomRight = WMSZ_BOTTOMRIGHT as _,
+}
+
+impl SizingEdge {
+    pub fn from_code(code: WPARAM) -> Option<Self> {
+        [
+            SizingEdge::Left,
+            SizingEdge::Right,
+            SizingEdge::Top,
+            SizingEdge::TopLeft,
+            SizingEdge::TopRight,
+            SizingEdge::Bottom,
+            SizingEdge::BottomLeft,
+            SizingEdge::BottomRight
+        ].into_iter().find(|&edge| edge as WPARAM == code)
+    }
+}
+
+#[repr(usize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone)]
+pub enum IconSize {
+    Small = ICON_SMALL as _,
+    Big = ICON_BIG as _,
+    Small2 = ICON_SMALL2 as _,
+}
+
+impl IconSize {
+    pub fn from_code(code: usize) -> Option<Self> {
+        [
+            IconSize::Small,
+            IconSize::Big,
+            IconSize::Small2
+        ].into_iter().find(|&size| size as usize == code)
+    }
+
+    pub fn into_code(self) -> usize {
+        self as usize
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get(self, window: HWND) -> Option<HICON> {
+        let icon = unsafe { SendMessageW(window, WM_GETICON, self as WPARAM, 0) } as HICON;
+        if icon.is_null() { None } else { Some(icon) }
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns the icon previously associated with the window.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn set(self, window: HWND, icon: HICON) -> Option<HICON> {
+        let previous = unsafe { SendMessageW(window, WM_SETICON, self as WPARAM, icon as LPARAM) } as HICON;
+        if previous.is_null() { None } else { Some(previous) }
+    }
+}
+
+#[repr(isize)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum WindowShown {
+    OtherUnZoom = SW_OTHERUNZOOM,
+    OtherZoom = SW_OTHERZOOM,
+    ParentClosing = SW_PARENTCLOSING,
+    ParentOpening = SW_PARENTOPENING,
+}
+
+impl WindowShown {
+    pub fn from_code(code: LPARAM) -> Option<Self> {
+        [
+            WindowShown::OtherUnZoom,
+            WindowShown::OtherZoom,
+            WindowShown::ParentClosing,
+            WindowShown::ParentOpening
+        ].into_iter().find(|&shown| shown as LPARAM == code)
+    }
+
+    pub fn into_code(self) -> LPARAM {
+        self as LPARAM
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ShowWindowMessage {
+    pub shown: bool,
+    pub reason: Option<WindowShown>
+}
+
+#[repr(usize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone)]
+pub enum WindowResizing {
+    MaxHide = SIZE_MAXHIDE,
+    Maximized = SIZE_MAXIMIZED,
+    MaxShow = SIZE_MAXSHOW,
+    Minimized = SIZE_MINIMIZED,
+    Restored = SIZE_RESTORED,
+}
+
+impl WindowResizing {
+    pub fn from_code(code: usize) -> Option<Self> {
+        [
+            WindowResizing::MaxHide,
+            WindowResizing::Maximized,
+            WindowResizing::MaxShow,
+            WindowResizing::Minimized,
+            WindowResizing::Restored
+        ].into_iter().find(|&resizing| resizing as usize == code)
+    }
+
+    pub fn into_code(self) -> usize {
+        self as usize
+    }
+}
+
+#[repr(usize)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum PowerEvent {
+    ApmPowerStatusChange = PBT_APMPOWERSTATUSCHANGE,
+    ApmResumeAutomatic = PBT_APMRESUMEAUTOMATIC,
+    ApmResumeSuspend = PBT_APMRESUMESUSPEND,
+    ApmSuspend = PBT_APMSUSPEND,
+    PowerSettingsChange = PBT_POWERSETTINGCHANGE,
+}
+
+impl PowerEvent {
+    pub fn from_code(code: usize) -> Option<Self> {
+        [
+            PowerEvent::ApmPowerStatusChange,
+            PowerEvent::ApmResumeAutomatic,
+            PowerEvent::ApmResumeSuspend,
+            PowerEvent::ApmSuspend,
+            PowerEvent::PowerSettingsChange
+        ].into_iter().find(|&event| event as usize == code)
+    }
+
+    pub fn into_code(self) -> usize {
+        self as usize
+    }
+}
+
+/// The `WM_POWER` codes that predate `WM_POWERBROADCAST`.
+#[repr(usize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LegacyPowerCode {
+    SuspendRequest = PWR_SUSPENDREQUEST as _,
+    SuspendResume = PWR_SUSPENDRESUME as _,
+    CriticalResume = PWR_CRITICALRESUME as _,
+}
+
+impl LegacyPowerCode {
+    pub fn from_code(code: usize) -> Option<Self> {
+        [
+            LegacyPowerCode::SuspendRequest,
+            LegacyPowerCode::SuspendResume,
+            LegacyPowerCode::CriticalResume
+        ].into_iter().find(|&power| power as usize == code)
+    }
+
+    pub fn into_code(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PowerMessage {
+    pub code: LegacyPowerCode
+}
+
+/// The reply to `LegacyPowerCode::SuspendRequest`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SuspendRequestResult {
+    Allow,
+    Deny,
+}
+
+impl SuspendRequestResult {
+    pub fn into_lresult(self) -> LRESULT {
+        match self {
+            SuspendRequestResult::Allow => PWR_OK as LRESULT,
+            SuspendRequestResult::Deny => PWR_FAIL as LRESULT,
+        }
+    }
+}
+
+#[repr(i32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum GwlStyle {
+    ExStyle = GWL_EXSTYLE,
+    Style = GWL_STYLE,
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct WindowStyles(u32); impl Debug;
+    pub maximize_box, _: 16;
+    pub minimize_box, _: 17;
+    pub thick_frame, _: 18;
+    pub sys_menu, _: 19;
+    pub hscroll, _: 20;
+    pub vscroll, _: 21;
+    pub dlg_frame, _: 22;
+    pub border, _: 23;
+    pub maximize, _: 24;
+    pub clip_children, _: 25;
+    pub clip_siblings, _: 26;
+    pub disabled, _: 27;
+    pub visible, _: 28;
+    pub minimize, _: 29;
+    pub child, _: 30;
+    pub popup, _: 31;
+}
+
+bitfield! {
+    #[derive(Copy, Clone)]
+    pub struct ExWindowStyles(u32); impl Debug;
+    pub dlg_modal_frame, _: 0;
+    pub no_parent_notify, _: 2;
+    pub topmost, _: 3;
+    pub accept_files, _: 4;
+    pub transparent, _: 5;
+    pub mdi_child, _: 6;
+    pub tool_window, _: 7;
+    pub window_edge, _: 8;
+    pub client_edge, _: 9;
+    pub context_help, _: 10;
+    pub right, _: 12;
+    pub rtl_reading, _: 13;
+    pub left_scrollbar, _: 14;
+    pub control_parent, _: 16;
+    pub static_edge, _: 17;
+    pub app_window, _: 18;
+    pub layered, _: 19;
+    pub no_inherit_layout, _: 20;
+    pub no_redirection_bitmap, _: 21;
+    pub layout_rtl, _: 22;
+    pub composited, _: 25;
+    pub no_activate, _: 27;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum StyleChange {
+    Style { old: WindowStyles, new: WindowStyles },
+    ExStyle { old: ExWindowStyles, new: ExWindowStyles },
+}
+
+/// What the `LRESULT` of a handled message means.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReturnSemantics {
+    /// Zero once handled; the result is otherwise unused.
+    ZeroIfHandled,
+    /// `TRUE`/`FALSE`, with a meaning specific to the message.
+    BoolResult,
+    /// A `HitTest` code.
+    HitTestCode,
+    /// An `HBRUSH` used to paint the control.
+    BrushHandle,
+    /// A handle such as an `HICON` or `HFONT`.
+    Handle,
+    /// A message-specific value such as a length, an index or a flag set.
+    Value,
+}
+
+/// A handler's result, for messages where `expects_reply` is true.
+#[derive(Debug, Copy, Clone)]
+pub enum Reply {
+    HitTest(HitTest),
+    DlgCode(DlgCode),
+    Bool(bool),
+    Length(usize),
+    /// Zero, for messages whose result is unused.
+    Ignored,
+}
+
+impl Reply {
+    pub fn into_lresult(self) -> LRESULT {
+        match self {
+            Reply::HitTest(hit_test) => hit_test.into_lresult(),
+            Reply::DlgCode(code) => code.into_lresult(),
+            Reply::Bool(value) => value as LRESULT,
+            Reply::Length(length) => length as LRESULT,
+            Reply::Ignored => 0,
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MessageCategory {
+    Mouse,
+    Keyboard,
+    NonClient,
+    Clipboard,
+    Ime,
+    Mdi,
+    Dwm,
+    Pointer,
+    Menu,
+    ControlColor,
+    Other,
+}
+
+#[cfg(target_pointer_width = "64")]
+const _NC_SIZE_ASSERT: [u8; 16] = [0; size_of::<NcSizeParams>()];
+#[cfg(target_pointer_width = "32")]
+const _NC_SIZE_ASSERT: [u8; 8] = [0; size_of::<NcSizeParams>()];
+
+#[repr(usize)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum NcSizeParams {
+    ValidClientArea {
+        data: Option<NonNull<NCCALCSIZE_PARAMS>>
+    } = TRUE as _,
+    Rect {
+        data: Option<NonNull<RECT>>
+    } = FALSE as _,
+}
+
+
+#[cfg(target_pointer_width = "64")]
+const _RAW_SIZE_ASSERT: [u8; 24] = [0; size_of::<RawEvent>()];
+#[cfg(target_pointer_width = "32")]
+const _RAW_SIZE_ASSERT: [u8; 12] = [0; size_of::<RawEvent>()];
+const _WM_SIZE_ASSERT: [u8; 24] = [0; size_of::<WindowMessage>()];
+
+#[derive(Debug, Copy, Clone)]
+pub enum WindowEvent {
+    Message(WindowMessage),
+    User(RawEvent),
+    App(RawEvent),
+    String(RawEvent),
+    Reserved(RawEvent),
+    /// Produced by `parse_checked` for input `parse` cannot represent soundly.
+    Unknown(RawEvent),
+}
+
+/// The kind of event and its original triple, named where `message_name` knows the id.
+impl fmt::Display for WindowEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let raw = self.into_raw();
+        let kind = match self {
+            WindowEvent::Message(_) => message_name(raw.msg).unwrap_or("Message"),
+            WindowEvent::User(_) => "User",
+            WindowEvent::App(_) => "App",
+            WindowEvent::String(_) => "String",
+            WindowEvent::Reserved(_) => "Reserved",
+            WindowEvent::Unknown(_) => "Unknown",
+        };
+        write!(f, "{} {}", kind, raw)
+    }
+}
+
+/// Filters a stream of events down to one kind of message.
+pub trait WindowEventExt: Iterator<Item = WindowEvent> + Sized {
+    fn messages(self) -> impl Iterator<Item = WindowMessage> {
+        self.filter_map(|event| match event {
+            WindowEvent::Message(message) => Some(message),
+            _ => None
+        })
+    }
+
+    fn keys(self) -> impl Iterator<Item = KeyMessage> {
+        self.messages().filter_map(|message| message.as_key())
+    }
+
+    fn mouse_buttons(self) -> impl Iterator<Item = MouseButtonMessage> {
+        self.messages().filter_map(|message| message.as_mouse_button())
+    }
+}
+
+impl<I: Iterator<Item = WindowEvent>> WindowEventExt for I {}
+
+fn fields_valid(msg: UINT, w_param: WPARAM) -> bool {
+    const ICON_SMALL_W: WPARAM = ICON_SMALL as _;
+    const ICON_BIG_W: WPARAM = ICON_BIG as _;
+    const ICON_SMALL2_W: WPARAM = ICON_SMALL2 as _;
+    match msg {
+        WM_ACTIVATE => matches!(w_param as WORD, WA_ACTIVE | WA_CLICKACTIVE | WA_INACTIVE),
+        WM_SIZE => matches!(w_param, SIZE_RESTORED | SIZE_MINIMIZED | SIZE_MAXIMIZED | SIZE_MAXSHOW | SIZE_MAXHIDE),
+        WM_STYLECHANGING | WM_STYLECHANGED => matches!(w_param as i32, GWL_STYLE | GWL_EXSTYLE),
+        WM_GETICON | WM_SETICON => matches!(w_param, ICON_SMALL_W | ICON_BIG_W | ICON_SMALL2_W),
+        WM_NCCALCSIZE => w_param <= 1,
+        WM_POWERBROADCAST => matches!(
+            w_param,
+            PBT_APMPOWERSTATUSCHANGE | PBT_APMRESUMEAUTOMATIC | PBT_APMRESUMESUSPEND | PBT_APMSUSPEND | PBT_POWERSETTINGCHANGE
+        ),
+        _ => true
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct TargetedEvent {
+    pub target: HWND,
+    pub event: WindowEvent,
+    raw: RawEvent
+}
+
+impl TargetedEvent {
+    pub fn raw(&self) -> RawEvent {
+        self.raw
+    }
+
+    #[cfg(feature = "std")]
+    /// Forwards the original message to `DefWindowProcW`.
+    pub fn default(&self) -> LRESULT {
+        unsafe { DefWindowProcW(self.target, self.raw.msg, self.raw.w_param, self.raw.l_param) }
+    }
+}
+
+/// A message alongside the exact triple it was parsed from, for forwarding without the
+/// lossy re-packing of `into_raw`.
+#[derive(Debug, Copy, Clone)]
+pub struct ParsedMessage {
+    pub typed: WindowMessage,
+    raw: RawEvent
+}
+
+impl ParsedMessage {
+    pub fn raw(&self) -> RawEvent {
+        self.raw
+    }
+
+    #[cfg(feature = "std")]
+    /// Forwards the original message to `DefWindowProcW`.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn reply_default(&self, window: HWND) -> LRESULT {
+        unsafe { DefWindowProcW(window, self.raw.msg, self.raw.w_param, self.raw.l_param) }
+    }
+}
+
+#[cfg(feature = "std")]
+type Handler<T> = Box<dyn FnMut(T) -> Option<LRESULT>>;
+
+#[cfg(feature = "std")]
+/// Collects closures for the kinds of message a window handles. A closure returning `None`
+/// defers to `DefWindowProcW`, as do messages no closure is registered for.
+#[derive(Default)]
+pub struct WindowProcBuilder {
+    mouse: Option<Handler<MouseButtonMessage>>,
+    key: Option<Handler<KeyMessage>>,
+    paint: Option<Handler<PaintContext>>,
+    other: Option<Handler<WindowEvent>>
+}
+
+#[cfg(feature = "std")]
+impl WindowProcBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on_mouse(self, handler: impl FnMut(MouseButtonMessage) -> Option<LRESULT> + 'static) -> Self {
+        WindowProcBuilder { mouse: Some(Box::new(handler)), ..self }
+    }
+
+    pub fn on_key(self, handler: impl FnMut(KeyMessage) -> Option<LRESULT> + 'static) -> Self {
+        WindowProcBuilder { key: Some(Box::new(handler)), ..self }
+    }
+
+    /// The paint is ended when the handler drops the context.
+    pub fn on_paint(self, handler: impl FnMut(PaintContext) -> Option<LRESULT> + 'static) -> Self {
+        WindowProcBuilder { paint: Some(Box::new(handler)), ..self }
+    }
+
+    /// Everything the other handlers are not registered for.
+    pub fn on_other(self, handler: impl FnMut(WindowEvent) -> Option<LRESULT> + 'static) -> Self {
+        WindowProcBuilder { other: Some(Box::new(handler)), ..self }
+    }
+
+    pub fn build(self) -> WindowProc {
+        WindowProc { handlers: self }
+    }
+}
+
+#[cfg(feature = "std")]
+/// Call it from the window procedure, typically kept in the window's user data.
+pub struct WindowProc {
+    handlers: WindowProcBuilder
+}
+
+#[cfg(feature = "std")]
+impl WindowProc {
+    /// The reply of the closure the message was routed to, or `None` to defer.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn dispatch(&mut self, window: HWND, msg: UINT, w_param: WPARAM, l_param: LPARAM) -> Option<LRESULT> {
+        let handlers = &mut self.handlers;
+        let event = WindowEvent::parse_checked(msg, w_param, l_param);
+        if let WindowEvent::Message(message) = event {
+            if let (Some(handler), Some(mouse)) = (&mut handlers.mouse, message.as_mouse_button()) {
+                return handler(mouse);
+            }
+            if let (Some(handler), Some(key)) = (&mut handlers.key, message.as_key()) {
+                return handler(key);
+            }
+            if let (Some(handler), WindowMessage::Paint) = (&mut handlers.paint, message) {
+                return handler(message.begin_paint(window)?);
+            }
+        }
+        handlers.other.as_mut()?(event)
+    }
+
+    /// Dispatches the message, forwarding the original triple to `DefWindowProcW` when
+    /// nothing handled it.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn call(&mut self, window: HWND, msg: UINT, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+        self.dispatch(window, msg, w_param, l_param)
+            .unwrap_or_else(|| unsafe { DefWindowProcW(window, msg, w_param, l_param) })
+    }
+}
+
+/// A message borrowed for the duration of the window procedure call that received it.
+/// Unlike a copied `WindowMessage`, the structures it hands out cannot outlive that call.
+pub struct WindowMessageRef<'a> {
+    message: WindowMessage,
+    raw: &'a RawEvent
+}
+
+impl<'a> WindowMessageRef<'a> {
+    pub fn id(&self) -> UINT {
+        self.raw.msg
+    }
+
+    pub fn raw(&self) -> RawEvent {
+        *self.raw
+    }
+
+    /// The message itself, as long as copying it cannot carry a pointer or handle away.
+    pub fn scalar(&self) -> Option<WindowMessage> {
+        WindowEvent::parse_const(self.raw.msg, self.raw.w_param, self.raw.l_param)
+    }
+
+    /// # Safety
+    /// Pointers in the returned message must not be dereferenced after the window procedure
+    /// call returns.
+    pub unsafe fn into_message(self) -> WindowMessage {
+        self.message
+    }
+
+    pub fn create_struct(&self) -> Option<&'a CREATESTRUCTA> {
+        match self.message {
+            WindowMessage::Create { data: Some(data), .. } => Some(unsafe { data.as_ref() }),
+            _ => None
+        }
+    }
+
+    /// Handlers of `WindowPosChanging` may adjust it to change the new placement.
+    pub fn window_pos(&mut self) -> Option<&mut WINDOWPOS> {
+        match self.message {
+            WindowMessage::WindowPosChanging { data: Some(mut data), .. }
+            | WindowMessage::WindowPosChanged { data: Some(mut data), .. } => Some(unsafe { data.as_mut() }),
+            _ => None
+        }
+    }
+
+    pub fn min_max_info(&mut self) -> Option<&mut MINMAXINFO> {
+        match self.message {
+            WindowMessage::GetMinMaxInfo { data: Some(mut data), .. } => Some(unsafe { data.as_mut() }),
+            _ => None
+        }
+    }
+}
+
+impl WindowEvent {
+    /// Borrows `raw` for as long as the pointers it carries may be used: build it on the
+    /// window procedure's stack. `None` where `try_parse` would not give a `Message`.
+    pub fn parse_ref(raw: &RawEvent) -> Option<WindowMessageRef<'_>> {
+        match Self::try_parse(raw.msg, raw.w_param, raw.l_param)? {
+            WindowEvent::Message(message) => Some(WindowMessageRef { message, raw }),
+            _ => None
+        }
+    }
+
+    /// `None` where `try_parse` would not give a `Message`.
+    pub fn parse_keeping_raw(msg: UINT, w_param: WPARAM, l_param: LPARAM) -> Option<ParsedMessage> {
+        match Self::try_parse(msg, w_param, l_param)? {
+            WindowEvent::Message(typed) => Some(ParsedMessage { typed, raw: RawEvent { msg, w_param, l_param } }),
+            _ => None
+        }
+    }
+
+    /// Like `parse`, but `None` for ids below `WM_USER` that no `WindowMessage` variant has,
+    /// and for parameters an enum field cannot hold; `parse` is undefined behavior for both.
+    pub fn try_parse(msg: UINT, w_param: WPARAM, l_param: LPARAM) -> Option<Self> {
+        if msg < WM_USER && (message_name(msg).is_none() || !fields_valid(msg, w_param)) {
+            return None;
+        }
+        Some(Self::parse(msg, w_param, l_param))
+    }
+
+    /// Never undefined behavior, whatever the input.
+    pub fn parse_checked(msg: UINT, w_param: WPARAM, l_param: LPARAM) -> Self {
+        Self::try_parse(msg, w_param, l_param).unwrap_or(Self::Unknown(RawEvent { msg, w_param, l_param }))
+    }
+
+    /// `WM_USER + offset`, as `parse` would give it; `None` when that leaves the `WM_USER`
+    /// range.
+    pub const fn user(offset: UINT, w_param: WPARAM, l_param: LPARAM) -> Option<Self> {
+        match WM_USER.checked_add(offset) {
+            Some(msg) if msg < WM_APP => Some(Self::User(RawEvent { msg: offset, w_param, l_param })),
+            _ => None
+        }
+    }
+
+    /// `WM_APP + offset`, as `parse` would give it; `None` when that leaves the `WM_APP`
+    /// range.
+    pub const fn app(offset: UINT, w_param: WPARAM, l_param: LPARAM) -> Option<Self> {
+        match WM_APP.checked_add(offset) {
+            Some(msg) if msg < 0xC000 => Some(Self::App(RawEvent { msg: offset, w_param, l_param })),
+            _ => None
+        }
+    }
+
+    /// `id` is the absolute id returned by `RegisterWindowMessage`; `None` outside the
+    /// registered string range.
+    pub const fn string(id: UINT, w_param: WPARAM, l_param: LPARAM) -> Option<Self> {
+        match id.checked_sub(WM_APP) {
+            Some(msg) if id >= 0xC000 && id < 0xFFFF => Some(Self::String(RawEvent { msg, w_param, l_param })),
+            _ => None
+        }
+    }
+
+    pub fn into_raw(self) -> RawEvent {
+        match self {
+            Self::Message(message) => message.into_raw(),
+            Self::User(raw) => RawEvent { msg: raw.msg + WM_USER, ..raw },
+            Self::App(raw) | Self::String(raw) => RawEvent { msg: raw.msg + WM_APP, ..raw },
+            Self::Reserved(raw) => RawEvent { msg: raw.msg + 0xFFFF, ..raw },
+            Self::Unknown(raw) => raw,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// Forwards the event to `DefWindowProcW` under its original id, undoing the rebasing
+    /// of `User`, `App`, `String` and `Reserved` ids. The parameters are passed through as
+    /// received rather than re-packed, since `into_raw` drops bits that unit variants and
+    /// narrowed fields don't keep.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn reply_default(self, window: HWND, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+        let msg = self.into_raw().msg;
+        unsafe { DefWindowProcW(window, msg, w_param, l_param) }
+    }
+
+    pub fn parse_with_target(target: HWND, msg: UINT, w_param: WPARAM, l_param: LPARAM) -> TargetedEvent {
+        TargetedEvent {
+            target,
+            event: Self::parse_checked(msg, w_param, l_param),
+            raw: RawEvent { msg, w_param, l_param },
+        }
+    }
+
+    pub fn parse(msg: UINT, w_param: WPARAM, l_param: LPARAM) -> Self {
+        const WM_USER_1: UINT = WM_USER - 1;
+        const WM_APP_1: UINT = WM_APP - 1;
+        const WM_STRING: UINT = 0xC000;
+        const WM_STRING_1: UINT = WM_STRING - 1;
+        const WM_RESERVED: UINT = 0xFFFF;
+        const WM_RESERVED_1: UINT = WM_RESERVED - 1;
+        match msg {
+            0..=WM_USER_1 => {
+                Self::Message(unsafe {
+                    UWM {
+                        raw: RawEvent { msg, w_param, l_param }
+                    }.typed
+                })
+            },
+            WM_USER..=WM_APP_1 => {
+                Self::User(RawEvent { msg: msg - WM_USER, w_param, l_param })
+            },
+            WM_APP..=WM_STRING_1 => {
+                Self::App(RawEvent { msg: msg - WM_APP, w_param, l_param })
+            },
+            WM_STRING..=WM_RESERVED_1 => {
+                Self::String(RawEvent { msg: msg - WM_APP, w_param, l_param })
+            },
+            WM_RESERVED.. => {
+                Self::Reserved(RawEvent { msg: msg - WM_RESERVED, w_param, l_param })
+            }
+        }
+    }
+
+    /// Covers the input messages and other variants without pointers, handles or enum fields,
+    /// returning `None` for everything else.
+    pub const fn parse_const(msg: UINT, w_param: WPARAM, l_param: LPARAM) -> Option<WindowMessage> {
+        let pos = MousePos::from_lparam(l_param);
+        let info = KeyInfo(l_param as u64);
+        let (lo, hi) = (w_param as WORD, (w_param >> 16) as WORD);
+        Some(match msg {
+            WM_MOUSEMOVE => WindowMessage::MouseMove { modifiers: w_param, pos },
+            WM_MOUSEHOVER => WindowMessage::MouseHover { modifiers: w_param, pos },
+            WM_LBUTTONDOWN => WindowMessage::LButtonDown { modifiers: w_param, pos },
+            WM_LBUTTONUP => WindowMessage::LButtonUp { modifiers: w_param, pos },
+            WM_LBUTTONDBLCLK => WindowMessage::LButtonDblClk { modifiers: w_param, pos },
+            WM_RBUTTONDOWN => WindowMessage::RButtonDown { modifiers: w_param, pos },
+            WM_RBUTTONUP => WindowMessage::RButtonUp { modifiers: w_param, pos },
+            WM_RBUTTONDBLCLK => WindowMessage::RButtonDblClk { modifiers: w_param, pos },
+            WM_MBUTTONDOWN => WindowMessage::MButtonDown { modifiers: w_param, pos },
+            WM_MBUTTONUP => WindowMessage::MButtonUp { modifiers: w_param, pos },
+            WM_MBUTTONDBLCLK => WindowMessage::MButtonDblClk { modifiers: w_param, pos },
+            WM_XBUTTONDOWN => WindowMessage::XButtonDown { _align: [], modifiers: lo, button: hi, pos },
+            WM_XBUTTONUP => WindowMessage::XButtonUp { _align: [], modifiers: lo, button: hi, pos },
+            WM_XBUTTONDBLCLK => WindowMessage::XButtonDblClk { _align: [], modifiers: lo, button: hi, pos },
+            WM_MOUSEWHEEL => WindowMessage::MouseWheel { _align: [], modifiers: lo, delta: hi, pos },
+            WM_MOUSEHWHEEL => WindowMessage::MouseHWheel { _align: [], modifiers: lo, delta: hi, pos },
+            WM_NCMOUSEMOVE => WindowMessage::NcMouseMove { hit_test: w_param, pos },
+            WM_NCLBUTTONDOWN => WindowMessage::NclButtonDown { hit_test: w_param, pos },
+            WM_NCLBUTTONUP => WindowMessage::NclButtonUp { hit_test: w_param, pos },
+            WM_NCLBUTTONDBLCLK => WindowMessage::NclButtonDblClk { hit_test: w_param, pos },
+            WM_NCRBUTTONDOWN => WindowMessage::NcRButtonDown { hit_test: w_param, pos },
+            WM_NCRBUTTONUP => WindowMessage::NcRButtonUp { hit_test: w_param, pos },
+            WM_NCRBUTTONDBLCLK => WindowMessage::NcRButtonDblClk { hit_test: w_param, pos },
+            WM_NCMBUTTONDOWN => WindowMessage::NcMButtonDown { hit_test: w_param, pos },
+            WM_NCMBUTTONUP => WindowMessage::NcMButtonUp { hit_test: w_param, pos },
+            WM_NCMBUTTONDBLCLK => WindowMessage::NcMButtonDblClk { hit_test: w_param, pos },
+            WM_NCXBUTTONDOWN => WindowMessage::NcXButtonDown { _align: [], hit_test: lo, button: hi, pos },
+            WM_NCXBUTTONUP => WindowMessage::NcXButtonUp { _align: [], hit_test: lo, button: hi, pos },
+            WM_NCXBUTTONDBLCLK => WindowMessage::NcXButtonDblClk { _align: [], hit_test: lo, button: hi, pos },
+            WM_KEYDOWN => WindowMessage::KeyDown { key_code: w_param, info },
+            WM_KEYUP => WindowMessage::KeyUp { key_code: w_param, info },
+            WM_SYSKEYDOWN => WindowMessage::SysKeyDown { key_code: w_param, info },
+            WM_SYSKEYUP => WindowMessage::SysKeyUp { key_code: w_param, info },
+            WM_NULL => WindowMessage::Null,
+            WM_DESTROY => WindowMessage::Destroy,
+            WM_CLOSE => WindowMessage::Close,
+            WM_QUIT => WindowMessage::Quit,
+            WM_MOUSELEAVE => WindowMessage::MouseLeave,
+            WM_CHAR => WindowMessage::Char { code_unit: w_param, info },
+            WM_DEADCHAR => WindowMessage::DeadChar { code_unit: w_param, info },
+            WM_SYSCHAR => WindowMessage::SysChar { code_unit: w_param, info },
+            WM_UNICHAR => WindowMessage::UniChar { code_point: w_param, info },
+            WM_SYSCOMMAND => WindowMessage::SysCommand { command: w_param, pos },
+            WM_SYSDEADCHAR => WindowMessage::SysDeadChar { code_unit: w_param, info },
+            _ => return None
+        })
+    }
+}
+
+/// With the `serde` feature, variants carrying pointers or handles are skipped: serializing
+/// one of them fails, since the addresses mean nothing outside the dispatch that produced them.
+#[repr(u32)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone)]
+pub enum WindowMessage {
+    Null = WM_NULL,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Create {
+        _unused: WPARAM,
+        data: Option<NonNull<CREATESTRUCTA>> //CREATESTRUCTW?
+    } = WM_CREATE,
+    Destroy = WM_DESTROY,
+    Move {
+        _unused: WPARAM,
+        x: i16,
+        y: i16,
+        _unused2: u32
+    } = WM_MOVE,
+    Size {
+        resizing: WindowResizing,
+        width: i16,
+        height: i16,
+        _unused: u32
+    } = WM_SIZE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Activate {
+        _align: [WPARAM; 0],
+        state: WindowActivation,
+        minimized: WORD,
+        _unused: u32,
+        window: HWND
+    } = WM_ACTIVATE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    SetFocus {
+        window: Option<NonNull<HWND>>
+    } = WM_SETFOCUS,
+    KillFocus = WM_KILLFOCUS,
+    Enable = WM_ENABLE,
+    SetRedraw = WM_SETREDRAW,
+    SetText = WM_SETTEXT,
+    GetText = WM_GETTEXT,
+    GetTextLength = WM_GETTEXTLENGTH,
+    Paint = WM_PAINT,
+    Close = WM_CLOSE,
+    QueryEndSession {
+        _unused: WPARAM,
+        reason: LPARAM
+    } = WM_QUERYENDSESSION,
+    QueryOpen = WM_QUERYOPEN,
+    EndSession {
+        _align: [WPARAM; 0],
+        ending: BOOL,
+        _unused: u32,
+        reason: LPARAM
+    } = WM_ENDSESSION,
+    Quit = WM_QUIT,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    EraseBackground {
+        dc: HDC,
+        _unused: LPARAM
+    } = WM_ERASEBKGND,
+    SysColorChange = WM_SYSCOLORCHANGE,
+    ShowWindow {
+        _align: [WPARAM; 0],
+        shown: BOOL,
+        _unused: u32,
+        status: LPARAM
+    } = WM_SHOWWINDOW,
+    SettingChange = WM_SETTINGCHANGE,
+    DevModeChange = WM_DEVMODECHANGE,
+    ActivateApp {
+        _align: [WPARAM; 0],
+        activated: BOOL,
+        _unused: u32,
+        thread_id: DWORD,
+        _unused2: u32
+    } = WM_ACTIVATEAPP,
+    FontChange = WM_FONTCHANGE,
+    TimeChange = WM_TIMECHANGE,
+    CancelMode = WM_CANCELMODE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    SetCursor {
+        window: HWND,
+        hit_test: WORD,
+        trigger_message: WORD,
+        _unused: u32
+    } = WM_SETCURSOR,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    MouseActivate {
+        top_window: HWND,
+        hit_test: WORD,
+        trigger_message: WORD,
+        _unused: u32
+    } = WM_MOUSEACTIVATE,
+    ChildActivate = WM_CHILDACTIVATE,
+    QueueSync = WM_QUEUESYNC,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    GetMinMaxInfo {
+        _unused: WPARAM,
+        data: Option<NonNull<MINMAXINFO>>
+    } = WM_GETMINMAXINFO,
+    PaintIcon = WM_PAINTICON,
+    IconEraseBackground = WM_ICONERASEBKGND,
+    NextDialogCtl {
+        target: WPARAM,
+        by_handle: LPARAM
+    } = WM_NEXTDLGCTL,
+    SpoolerStatus {
+        status: WPARAM,
+        jobs: LPARAM
+    } = WM_SPOOLERSTATUS,
+    DrawItem = WM_DRAWITEM,
+    MeasureItem = WM_MEASUREITEM,
+    DeleteItem = WM_DELETEITEM,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    VKeyToItem {
+        _align: [WPARAM; 0],
+        key: WORD,
+        caret: WORD,
+        _unused: u32,
+        list: HWND
+    } = WM_VKEYTOITEM,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CharToItem {
+        _align: [WPARAM; 0],
+        character: WORD,
+        caret: WORD,
+        _unused: u32,
+        list: HWND
+    } = WM_CHARTOITEM,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    SetFont {
+        font: HFONT,
+        redraw: LPARAM
+    } = WM_SETFONT,
+    /// Replied to with the `HFONT` from the last `SetFont` as the `LRESULT`, or 0 while the
+    /// control draws with the system font.
+    GetFont = WM_GETFONT,
+    SetHotkey = WM_SETHOTKEY,
+    GetHotkey = WM_GETHOTKEY,
+    /// Sent to a minimized window without a class icon that is being dragged; replied to
+    /// with a `QueryDragIconReply`.
+    QueryDragIcon = WM_QUERYDRAGICON,
+    CompareItem = WM_COMPAREITEM,
+    GetObject {
+        flags: WPARAM,
+        object_id: LPARAM
+    } = WM_GETOBJECT,
+    Compacting {
+        ratio: WPARAM,
+        _unused: LPARAM
+    } = WM_COMPACTING,
+    CommNotify {
+        device_id: WPARAM,
+        events: LPARAM
+    } = WM_COMMNOTIFY,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    WindowPosChanging {
+        _unused: WPARAM,
+        data: Option<NonNull<WINDOWPOS>>
+    } = WM_WINDOWPOSCHANGING,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    WindowPosChanged {
+        _unused: WPARAM,
+        data: Option<NonNull<WINDOWPOS>>
+    } = WM_WINDOWPOSCHANGED,
+    Power {
+        code: WPARAM,
+        _unused: LPARAM
+    } = WM_POWER,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CopyData {
+        sender: HWND,
+        data: Option<NonNull<COPYDATASTRUCT>>
+    } = WM_COPYDATA,
+    CancelJournal = WM_CANCELJOURNAL,
+    Notify = WM_NOTIFY,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    InputLangChangeRequest {
+        flags: WPARAM,
+        layout: HKL
+    } = WM_INPUTLANGCHANGEREQUEST,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    InputLangChange {
+        charset: WPARAM,
+        layout: HKL
+    } = WM_INPUTLANGCHANGE,
+    TCard {
+        action: WPARAM,
+        data: LPARAM
+    } = WM_TCARD,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Help {
+        _unused: WPARAM,
+        info: Option<NonNull<HELPINFO>>
+    } = WM_HELP,
+    UserChanged = WM_USERCHANGED,
+    NotifyFormat = WM_NOTIFYFORMAT,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ContextMenu {
+        window: HWND,
+        pos: MousePos
+    } = WM_CONTEXTMENU,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    StyleChanging {
+        _align: [WPARAM; 0],
+        style: GwlStyle,
+        _unused: u32,
+        data: Option<NonNull<STYLESTRUCT>>
+    } = WM_STYLECHANGING,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    StyleChanged {
+        _align: [WPARAM; 0],
+        style: GwlStyle,
+        _unused: u32,
+        data: Option<NonNull<STYLESTRUCT>>
+    } = WM_STYLECHANGED,
+    DisplayChange = WM_DISPLAYCHANGE,
+    GetIcon {
+        size: IconSize,
+        dpi: LPARAM
+    } = WM_GETICON,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    SetIcon {
+        size: IconSize,
+        icon: HICON
+    } = WM_SETICON,
+    NcCreate = WM_NCCREATE,
+    NcDestroy = WM_NCDESTROY,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    NcCalcSize {
+        params: NcSizeParams,
+    } = WM_NCCALCSIZE,
+    NcHitTest {
+        _unused: WPARAM,
+        pos: MousePos
+    } = WM_NCHITTEST,
+    NcPaint {
+        update_region: WPARAM,
+        _unused: LPARAM
+    } = WM_NCPAINT,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    NcActivate {
+        _align: [WPARAM; 0],
+        active: BOOL,
+        _unused: u32,
+        update_region: LPARAM
+    } = WM_NCACTIVATE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    GetDlgCode {
+        key: WPARAM,
+        msg: Option<NonNull<MSG>>
+    } = WM_GETDLGCODE,
+    SyncPaint = WM_SYNCPAINT,
+    NcMouseMove {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCMOUSEMOVE,
+    NclButtonDown {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCLBUTTONDOWN,
+    NclButtonUp {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCLBUTTONUP,
+    NclButtonDblClk {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCLBUTTONDBLCLK,
+    NcRButtonDown {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCRBUTTONDOWN,
+    NcRButtonUp {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCRBUTTONUP,
+    NcRButtonDblClk {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCRBUTTONDBLCLK,
+    NcMButtonDown {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCMBUTTONDOWN,
+    NcMButtonUp {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCMBUTTONUP,
+    NcMButtonDblClk {
+        hit_test: WPARAM,
+        pos: MousePos
+    } = WM_NCMBUTTONDBLCLK,
+    NcXButtonDown {
+        _align: [WPARAM; 0],
+        hit_test: WORD,
+        button: WORD,
+        pos: MousePos
+    } = WM_NCXBUTTONDOWN,
+    NcXButtonUp {
+        _align: [WPARAM; 0],
+        hit_test: WORD,
+        button: WORD,
+        pos: MousePos
+    } = WM_NCXBUTTONUP,
+    NcXButtonDblClk {
+        _align: [WPARAM; 0],
+        hit_test: WORD,
+        button: WORD,
+        pos: MousePos
+    } = WM_NCXBUTTONDBLCLK,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    InputDeviceChange {
+        change: WPARAM,
+        device: HANDLE
+    } = WM_INPUT_DEVICE_CHANGE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Input {
+        code: WPARAM,
+        handle: HRAWINPUT
+    } = WM_INPUT,
+    KeyDown {
+        key_code: WPARAM,
+        info: KeyInfo
+    } = WM_KEYDOWN,
+    KeyUp {
+        key_code: WPARAM,
+        info: KeyInfo
+    } = WM_KEYUP,
+    Char {
+        code_unit: WPARAM,
+        info: KeyInfo
+    } = WM_CHAR,
+    DeadChar {
+        code_unit: WPARAM,
+        info: KeyInfo
+    } = WM_DEADCHAR,
+    SysKeyDown {
+        key_code: WPARAM,
+        info: KeyInfo
+    } = WM_SYSKEYDOWN,
+    SysKeyUp {
+        key_code: WPARAM,
+        info: KeyInfo
+    } = WM_SYSKEYUP,
+    SysChar {
+        code_unit: WPARAM,
+        info: KeyInfo
+    } = WM_SYSCHAR,
+    SysDeadChar {
+        code_unit: WPARAM,
+        info: KeyInfo
+    } = WM_SYSDEADCHAR,
+    UniChar {
+        code_point: WPARAM,
+        info: KeyInfo
+    } = WM_UNICHAR,
+    ImeStartComposition = WM_IME_STARTCOMPOSITION,
+    ImeEndComposition = WM_IME_ENDCOMPOSITION,
+    ImeComposition = WM_IME_COMPOSITION,
+    InitDialog = WM_INITDIALOG,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Command {
+        _align: [WPARAM; 0],
+        id: WORD,
+        notification: WORD,
+        _unused: u32,
+        control: HWND
+    } = WM_COMMAND,
+    SysCommand {
+        command: WPARAM,
+        pos: MousePos
+    } = WM_SYSCOMMAND,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    TIMER {
+        id: usize,
+        callback: TIMERPROC
+    } = WM_TIMER,
+    HScroll = WM_HSCROLL,
+    VScroll = WM_VSCROLL,
+    InitMenu = WM_INITMENU,
+    InitMenuPopup = WM_INITMENUPOPUP,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Gesture {
+        _unused: WPARAM,
+        handle: HGESTUREINFO
+    } = WM_GESTURE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    GestureNotify {
+        _unused: WPARAM,
+        info: Option<NonNull<GESTURENOTIFYSTRUCT>>
+    } = WM_GESTURENOTIFY,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    MenuSelect {
+        _align: [WPARAM; 0],
+        item: WORD,
+        flags: WORD,
+        _unused: u32,
+        menu: HMENU
+    } = WM_MENUSELECT,
+    MenuChar = WM_MENUCHAR,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    EnterIdle {
+        reason: WPARAM,
+        window: HWND
+    } = WM_ENTERIDLE,
+    MenuRButtonUp = WM_MENURBUTTONUP,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    MenuDrag {
+        position: WPARAM,
+        menu: HMENU
+    } = WM_MENUDRAG,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    MenuGetObject {
+        _unused: WPARAM,
+        info: Option<NonNull<MENUGETOBJECTINFO>>
+    } = WM_MENUGETOBJECT,
+    UninitMenuPopup = WM_UNINITMENUPOPUP,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    MenuCommand {
+        index: WPARAM,
+        menu: HMENU
+    } = WM_MENUCOMMAND,
+    ChangeUiState {
+        _align: [WPARAM; 0],
+        action: WORD,
+        flags: WORD,
+        _unused: u32,
+        _unused2: LPARAM
+    } = WM_CHANGEUISTATE,
+    UpdateUiState {
+        _align: [WPARAM; 0],
+        action: WORD,
+        flags: WORD,
+        _unused: u32,
+        _unused2: LPARAM
+    } = WM_UPDATEUISTATE,
+    QueryUiState = WM_QUERYUISTATE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CtlColorMsgBox {
+        dc: HDC,
+        control: HWND
+    } = WM_CTLCOLORMSGBOX,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CtlColorEdit {
+        dc: HDC,
+        control: HWND
+    } = WM_CTLCOLOREDIT,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CtlColorListBox {
+        dc: HDC,
+        control: HWND
+    } = WM_CTLCOLORLISTBOX,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CtlColorBtn {
+        dc: HDC,
+        control: HWND
+    } = WM_CTLCOLORBTN,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CtlColorDlg {
+        dc: HDC,
+        control: HWND
+    } = WM_CTLCOLORDLG,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CtlColorScrollbar {
+        dc: HDC,
+        control: HWND
+    } = WM_CTLCOLORSCROLLBAR,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CtlColorStatic {
+        dc: HDC,
+        control: HWND
+    } = WM_CTLCOLORSTATIC,
+    MouseMove {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_MOUSEMOVE,
+    LButtonDown {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_LBUTTONDOWN,
+    LButtonUp {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_LBUTTONUP,
+    LButtonDblClk {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_LBUTTONDBLCLK,
+    RButtonDown {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_RBUTTONDOWN,
+    RButtonUp {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_RBUTTONUP,
+    RButtonDblClk {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_RBUTTONDBLCLK,
+    MButtonDown {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_MBUTTONDOWN,
+    MButtonUp {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_MBUTTONUP,
+    MButtonDblClk {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_MBUTTONDBLCLK,
+    MouseWheel {
+        _align: [WPARAM; 0],
+        modifiers: WORD,
+        delta: WORD,
+        pos: MousePos
+    } = WM_MOUSEWHEEL,
+    XButtonDown {
+        _align: [WPARAM; 0],
+        modifiers: WORD,
+        button: WORD,
+        pos: MousePos
+    } = WM_XBUTTONDOWN,
+    XButtonUp {
+        _align: [WPARAM; 0],
+        modifiers: WORD,
+        button: WORD,
+        pos: MousePos
+    } = WM_XBUTTONUP,
+    XButtonDblClk {
+        _align: [WPARAM; 0],
+        modifiers: WORD,
+        button: WORD,
+        pos: MousePos
+    } = WM_XBUTTONDBLCLK,
+    NcUahDrawCaption {
+        w: WPARAM,
+        l: LPARAM,
+    } = 0x00AE /*WM_NCUAHDRAWCAPTION*/,
+    NcUahDrawFrame {
+        w: WPARAM,
+        l: LPARAM,
+    } = 0x00AF /*WM_NCUAHDRAWFRAME*/,
+    MouseHWheel {
+        _align: [WPARAM; 0],
+        modifiers: WORD,
+        delta: WORD,
+        pos: MousePos
+    } = WM_MOUSEHWHEEL,
+    ParentNotify {
+        _align: [WPARAM; 0],
+        event: WORD,
+        id: WORD,
+        _unused: u32,
+        data: LPARAM
+    } = WM_PARENTNOTIFY,
+    EnterMenuLoop {
+        track_popup: WPARAM,
+        _unused: LPARAM
+    } = WM_ENTERMENULOOP,
+    ExitMenuLoop {
+        track_popup: WPARAM,
+        _unused: LPARAM
+    } = WM_EXITMENULOOP,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    NextMenu {
+        key: WPARAM,
+        data: Option<NonNull<MDINEXTMENU>>
+    } = WM_NEXTMENU,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Sizing {
+        edge: WPARAM,
+        rect: Option<NonNull<RECT>>
+    } = WM_SIZING,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CaptureChanged {
+        _unused: WPARAM,
+        window: HWND
+    } = WM_CAPTURECHANGED,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Moving {
+        _unused: WPARAM,
+        rect: Option<NonNull<RECT>>
+    } = WM_MOVING,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    PowerBroadcast {
+        event: PowerEvent,
+        data: Option<NonNull<POWERBROADCAST_SETTING>>
+    } = WM_POWERBROADCAST,
+    DeviceChange = WM_DEVICECHANGE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    MdiCreate {
+        _unused: WPARAM,
+        data: Option<NonNull<MDICREATESTRUCTA>> //MDICREATESTRUCTW?
+    } = WM_MDICREATE,
+    MdiDestroy = WM_MDIDESTROY,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    MdiActivate {
+        deactivated: HWND,
+        activated: HWND
+    } = WM_MDIACTIVATE,
+    MdiRestore = WM_MDIRESTORE,
+    MdiNext = WM_MDINEXT,
+    MdiMaximize = WM_MDIMAXIMIZE,
+    MdiTile = WM_MDITILE,
+    MdiCascade = WM_MDICASCADE,
+    MdiIconArrange = WM_MDIICONARRANGE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    MdiGetActive {
+        _unused: WPARAM,
+        maximized: Option<NonNull<BOOL>>
+    } = WM_MDIGETACTIVE,
+    MdiSetMenu = WM_MDISETMENU,
+    EnterSizeMove = WM_ENTERSIZEMOVE,
+    ExitSizeMove = WM_EXITSIZEMOVE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    DropFiles {
+        handle: HDROP,
+        _unused: LPARAM
+    } = WM_DROPFILES,
+    MdiRefreshMenu = WM_MDIREFRESHMENU,
+    PointerDeviceChange = WM_POINTERDEVICECHANGE,
+    PointerDeviceInRange = WM_POINTERDEVICEINRANGE,
+    PointerDeviceOutOfRange = WM_POINTERDEVICEOUTOFRANGE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Touch {
+        _align: [WPARAM; 0],
+        count: WORD,
+        _unused: WORD,
+        _unused2: u32,
+        handle: HTOUCHINPUT
+    } = WM_TOUCH,
+    NcPointerUpdate {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_NCPOINTERUPDATE,
+    NcPointerDown {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_NCPOINTERDOWN,
+    NcPointerUp {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_NCPOINTERUP,
+    PointerUpdate {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERUPDATE,
+    POINTERDOWN {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERDOWN,
+    POINTERUP {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERUP,
+    POINTERENTER {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERENTER,
+    POINTERLEAVE {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERLEAVE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    PointerActivate {
+        _align: [WPARAM; 0],
+        id: WORD,
+        hit_test: WORD,
+        _unused: u32,
+        top_window: HWND
+    } = WM_POINTERACTIVATE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    PointerCaptureChanged {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        window: HWND
+    } = WM_POINTERCAPTURECHANGED,
+    TouchHitTesting = WM_TOUCHHITTESTING,
+    PointerWheel {
+        _align: [WPARAM; 0],
+        id: WORD,
+        delta: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERWHEEL,
+    PointerHWheel {
+        _align: [WPARAM; 0],
+        id: WORD,
+        delta: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERHWHEEL,
+    PointerRoutedTo {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERROUTEDTO,
+    PointerRoutedAway {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERROUTEDAWAY,
+    PointerRoutedReleased {
+        _align: [WPARAM; 0],
+        id: WORD,
+        flags: WORD,
+        _unused: u32,
+        pos: MousePos
+    } = WM_POINTERROUTEDRELEASED,
+    ImeSetContext {
+        _align: [WPARAM; 0],
+        active: BOOL,
+        _unused: u32,
+        display_options: LPARAM
+    } = WM_IME_SETCONTEXT,
+    ImeNotify {
+        command: WPARAM,
+        data: LPARAM
+    } = WM_IME_NOTIFY,
+    ImeControl = WM_IME_CONTROL,
+    ImeCompositionFull = WM_IME_COMPOSITIONFULL,
+    ImeSelect = WM_IME_SELECT,
+    ImeChar = WM_IME_CHAR,
+    ImeRequest = WM_IME_REQUEST,
+    ImeKeydown = WM_IME_KEYDOWN,
+    ImeKeyup = WM_IME_KEYUP,
+    MouseHover {
+        modifiers: WPARAM,
+        pos: MousePos
+    } = WM_MOUSEHOVER,
+    MouseLeave = WM_MOUSELEAVE,
+    NcMouseHover = WM_NCMOUSEHOVER,
+    NcMouseLeave = WM_NCMOUSELEAVE,
+    WtsSessionChange {
+        event: WPARAM,
+        session_id: LPARAM
+    } = WM_WTSSESSION_CHANGE,
+    TabletFirst = WM_TABLET_FIRST,
+    TabletLast = WM_TABLET_LAST,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    DpiChanged {
+        _align: [WPARAM; 0],
+        dpi_x: WORD,
+        dpi_y: WORD,
+        _unused: u32,
+        suggested_rect: Option<NonNull<RECT>>
+    } = WM_DPICHANGED,
+    DpiChangedBeforeParent = WM_DPICHANGED_BEFOREPARENT,
+    DpiChangedAfterParent = WM_DPICHANGED_AFTERPARENT,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    GetDpiScaledSize {
+        dpi: WPARAM,
+        size: Option<NonNull<SIZE>>
+    } = WM_GETDPISCALEDSIZE,
+    Cut = WM_CUT,
+    Copy = WM_COPY,
+    Paste = WM_PASTE,
+    Clear = WM_CLEAR,
+    Undo = WM_UNDO,
+    RenderFormat = WM_RENDERFORMAT,
+    RenderAllFormats = WM_RENDERALLFORMATS,
+    DestroyClipboard = WM_DESTROYCLIPBOARD,
+    DrawClipboard = WM_DRAWCLIPBOARD,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    PaintClipboard {
+        viewer: HWND,
+        paint: HGLOBAL
+    } = WM_PAINTCLIPBOARD,
+    VScrollClipboard = WM_VSCROLLCLIPBOARD,
+    SizeClipboard = WM_SIZECLIPBOARD,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    AskCbFormatName {
+        capacity: WPARAM,
+        buffer: Option<NonNull<u16>>
+    } = WM_ASKCBFORMATNAME,
+    ChangeCbChain = WM_CHANGECBCHAIN,
+    HScrollClipboard = WM_HSCROLLCLIPBOARD,
+    QueryNewPalette = WM_QUERYNEWPALETTE,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    PaletteIsChanging {
+        origin: HWND,
+        _unused: LPARAM
+    } = WM_PALETTEISCHANGING,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    PaletteChanged {
+        origin: HWND,
+        _unused: LPARAM
+    } = WM_PALETTECHANGED,
+    Hotkey = WM_HOTKEY,
+    Print = WM_PRINT,
+    PrintClient = WM_PRINTCLIENT,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    AppCommand {
+        window: HWND,
+        keys: WORD,
+        command: WORD,
+        _unused: u32
+    } = WM_APPCOMMAND,
+    ThemeChanged = WM_THEMECHANGED,
+    ClipboardUpdate = WM_CLIPBOARDUPDATE,
+    DwmCompositionChanged = WM_DWMCOMPOSITIONCHANGED,
+    DwmNcRenderingChanged {
+        rendering_enabled: WPARAM,
+        _unused: LPARAM
+    } = WM_DWMNCRENDERINGCHANGED,
+    DwmColorizationColorChanged {
+        color: WPARAM,
+        opaque_blend: LPARAM
+    } = WM_DWMCOLORIZATIONCOLORCHANGED,
+    DwmWindowMaximizedChange {
+        maximized: WPARAM,
+        _unused: LPARAM
+    } = WM_DWMWINDOWMAXIMIZEDCHANGE,
+    DwmSendIconIcThumbnail {
+        _unused: WPARAM,
+        size: LPARAM
+    } = WM_DWMSENDICONICTHUMBNAIL,
+    DwmSendIconIcLivePreviewBitmap = WM_DWMSENDICONICLIVEPREVIEWBITMAP,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    GetTitleBarInfoEx {
+        _unused: WPARAM,
+        info: Option<NonNull<TITLEBARINFOEX>>
+    } = WM_GETTITLEBARINFOEX,
+    HandHeldFirst = WM_HANDHELDFIRST,
+    HandHeldLast = WM_HANDHELDLAST,
+    AfxFirst = WM_AFXFIRST,
+    AfxLast = WM_AFXLAST,
+    PenWinFirst = WM_PENWINFIRST,
+    PenWinLast = WM_PENWINLAST
+}
+
+impl WindowMessage {
+    pub fn mouse_button(action: MouseButtonAction, button: MouseButton, pos: MousePos, modifiers: Modifiers) -> Self {
+        MouseButtonMessage { action, button, pos, modifiers: modifiers.0, space: CoordSpace::Client }.into_window_message()
+    }
+
+    pub fn key(code: WPARAM, up: bool, sys: bool, info: KeyInfo) -> Self {
+        KeyMessage { up, sys, code, info }.into_window_message()
+    }
+
+    /// Each notch is one `WHEEL_DELTA`; positive notches scroll away from the user, or right.
+    /// `None` beyond the ±273 notches the 16-bit delta can hold.
+    pub fn mouse_wheel(axis: WheelAxis, notches: i16, pos: MousePos, modifiers: Modifiers) -> Option<Self> {
+        const WHEEL_DELTA: i16 = 120;
+        let (modifiers, delta) = (modifiers.0 as WORD, notches.checked_mul(WHEEL_DELTA)? as WORD);
+        Some(match axis {
+            WheelAxis::Vertical => WindowMessage::MouseWheel { _align: [], modifiers, delta, pos },
+            WheelAxis::Horizontal => WindowMessage::MouseHWheel { _align: [], modifiers, delta, pos },
+        })
+    }
+
+    pub fn id(&self) -> UINT {
+        // `repr(u32)` places the discriminant at the start of every variant
+        unsafe { *(self as *const Self as *const UINT) }
+    }
+
+    pub fn into_raw(self) -> RawEvent {
+        let (w_param, l_param) = match self {
+            WindowMessage::Create { _unused, data, .. } => (_unused, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::Move { _unused, x, y, _unused2, .. } => (_unused, (x as u16 as usize | (y as u16 as usize) << 16 | ((_unused2 as u64) << 32) as usize) as LPARAM),
+            WindowMessage::Size { resizing, width, height, _unused, .. } => (resizing as usize, (width as u16 as usize | (height as u16 as usize) << 16 | ((_unused as u64) << 32) as usize) as LPARAM),
+            WindowMessage::Activate { state, minimized, _unused, window, .. } => (state as usize | (minimized as usize) << 16 | ((_unused as u64) << 32) as usize, window as LPARAM),
+            WindowMessage::SetFocus { window, .. } => (window.map_or(0, |window| window.as_ptr() as usize), 0),
+            WindowMessage::QueryEndSession { _unused, reason, .. } => (_unused, reason),
+            WindowMessage::EndSession { ending, _unused, reason, .. } => (ending as u32 as usize | ((_unused as u64) << 32) as usize, reason),
+            WindowMessage::EraseBackground { dc, _unused, .. } => (dc as usize, _unused),
+            WindowMessage::ShowWindow { shown, _unused, status, .. } => (shown as u32 as usize | ((_unused as u64) << 32) as usize, status),
+            WindowMessage::ActivateApp { activated, _unused, thread_id, _unused2, .. } => (activated as u32 as usize | ((_unused as u64) << 32) as usize, (thread_id as usize | ((_unused2 as u64) << 32) as usize) as LPARAM),
+            WindowMessage::SetCursor { window, hit_test, trigger_message, _unused, .. } => (window as usize, (hit_test as usize | (trigger_message as usize) << 16 | ((_unused as u64) << 32) as usize) as LPARAM),
+            WindowMessage::MouseActivate { top_window, hit_test, trigger_message, _unused, .. } => (top_window as usize, (hit_test as usize | (trigger_message as usize) << 16 | ((_unused as u64) << 32) as usize) as LPARAM),
+            WindowMessage::AppCommand { window, keys, command, _unused, .. } => (window as usize, (keys as usize | (command as usize) << 16 | ((_unused as u64) << 32) as usize) as LPARAM),
+            WindowMessage::GetMinMaxInfo { _unused, data, .. } => (_unused, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::VKeyToItem { key, caret, _unused, list, .. } => (key as usize | (caret as usize) << 16 | ((_unused as u64) << 32) as usize, list as LPARAM),
+            WindowMessage::CharToItem { character, caret, _unused, list, .. } => (character as usize | (caret as usize) << 16 | ((_unused as u64) << 32) as usize, list as LPARAM),
+            WindowMessage::GetObject { flags, object_id, .. } => (flags, object_id),
+            WindowMessage::Compacting { ratio, _unused, .. } => (ratio, _unused),
+            WindowMessage::CommNotify { device_id, events, .. } => (device_id, events),
+            WindowMessage::Power { code, _unused, .. } => (code, _unused),
+            WindowMessage::TCard { action, data, .. } => (action, data),
+            WindowMessage::SetFont { font, redraw, .. } => (font as usize, redraw),
+            WindowMessage::InputLangChangeRequest { flags, layout, .. } => (flags, layout as LPARAM),
+            WindowMessage::InputLangChange { charset, layout, .. } => (charset, layout as LPARAM),
+            WindowMessage::WindowPosChanging { _unused, data, .. } => (_unused, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::WindowPosChanged { _unused, data, .. } => (_unused, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::CopyData { sender, data, .. } => (sender as usize, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::StyleChanging { style, _unused, data, .. } => (style as u32 as usize | ((_unused as u64) << 32) as usize, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::StyleChanged { style, _unused, data, .. } => (style as u32 as usize | ((_unused as u64) << 32) as usize, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::GetIcon { size, dpi, .. } => (size as usize, dpi),
+            WindowMessage::SetIcon { size, icon, .. } => (size as usize, icon as LPARAM),
+            WindowMessage::NcCalcSize { params } => match params {
+                NcSizeParams::ValidClientArea { data } => (TRUE as WPARAM, data.map_or(0, |data| data.as_ptr() as LPARAM)),
+                NcSizeParams::Rect { data } => (FALSE as WPARAM, data.map_or(0, |data| data.as_ptr() as LPARAM)),
+            },
+            WindowMessage::NcHitTest { _unused, pos, .. } => (_unused, pos.into_lparam()),
+            WindowMessage::NcPaint { update_region, _unused, .. } => (update_region, _unused),
+            WindowMessage::NcActivate { active, _unused, update_region, .. } => (active as u32 as usize | ((_unused as u64) << 32) as usize, update_region),
+            WindowMessage::GetDlgCode { key, msg, .. } => (key, msg.map_or(0, |msg| msg.as_ptr() as usize) as LPARAM),
+            WindowMessage::NcMouseMove { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::NclButtonDown { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::NclButtonUp { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::NclButtonDblClk { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::NcRButtonDown { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::NcRButtonUp { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::NcRButtonDblClk { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::NcMButtonDown { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::NcMButtonUp { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::NcMButtonDblClk { hit_test, pos, .. } => (hit_test, pos.into_lparam()),
+            WindowMessage::InputDeviceChange { change, device, .. } => (change, device as LPARAM),
+            WindowMessage::Input { code, handle, .. } => (code, handle as LPARAM),
+            WindowMessage::KeyDown { key_code, info, .. } => (key_code, info.0 as LPARAM),
+            WindowMessage::KeyUp { key_code, info, .. } => (key_code, info.0 as LPARAM),
+            WindowMessage::SysKeyDown { key_code, info, .. } => (key_code, info.0 as LPARAM),
+            WindowMessage::SysKeyUp { key_code, info, .. } => (key_code, info.0 as LPARAM),
+            WindowMessage::UniChar { code_point, info, .. } => (code_point, info.0 as LPARAM),
+            WindowMessage::Char { code_unit, info, .. }
+            | WindowMessage::DeadChar { code_unit, info, .. }
+            | WindowMessage::SysChar { code_unit, info, .. }
+            | WindowMessage::SysDeadChar { code_unit, info, .. } => (code_unit, info.0 as LPARAM),
+            WindowMessage::TIMER { id, callback, .. } => (id, callback.map_or(0, |callback| callback as usize) as LPARAM),
+            WindowMessage::Gesture { _unused, handle, .. } => (_unused, handle as LPARAM),
+            WindowMessage::GestureNotify { _unused, info, .. } => (_unused, info.map_or(0, |info| info.as_ptr() as usize) as LPARAM),
+            WindowMessage::MenuSelect { item, flags, _unused, menu, .. } => (item as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, menu as LPARAM),
+            WindowMessage::EnterIdle { reason, window, .. } => (reason, window as LPARAM),
+            WindowMessage::MouseMove { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::SysCommand { command, pos, .. } => (command, pos.into_lparam()),
+            WindowMessage::LButtonDown { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::LButtonUp { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::LButtonDblClk { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::RButtonDown { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::RButtonUp { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::RButtonDblClk { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::MButtonDown { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::MButtonUp { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::MButtonDblClk { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::MouseWheel { modifiers, delta, pos, .. } => (modifiers as usize | (delta as usize) << 16, pos.into_lparam()),
+            WindowMessage::XButtonDown { modifiers, button, pos, .. } => (modifiers as usize | (button as usize) << 16, pos.into_lparam()),
+            WindowMessage::XButtonUp { modifiers, button, pos, .. } => (modifiers as usize | (button as usize) << 16, pos.into_lparam()),
+            WindowMessage::XButtonDblClk { modifiers, button, pos, .. } => (modifiers as usize | (button as usize) << 16, pos.into_lparam()),
+            WindowMessage::NcXButtonDown { hit_test, button, pos, .. } => (hit_test as usize | (button as usize) << 16, pos.into_lparam()),
+            WindowMessage::NcXButtonUp { hit_test, button, pos, .. } => (hit_test as usize | (button as usize) << 16, pos.into_lparam()),
+            WindowMessage::NcXButtonDblClk { hit_test, button, pos, .. } => (hit_test as usize | (button as usize) << 16, pos.into_lparam()),
+            WindowMessage::NcUahDrawCaption { w, l, .. } => (w, l),
+            WindowMessage::NcUahDrawFrame { w, l, .. } => (w, l),
+            WindowMessage::MouseHWheel { modifiers, delta, pos, .. } => (modifiers as usize | (delta as usize) << 16, pos.into_lparam()),
+            WindowMessage::ParentNotify { event, id, _unused, data, .. } => (event as usize | (id as usize) << 16 | ((_unused as u64) << 32) as usize, data),
+            WindowMessage::NextMenu { key, data, .. } => (key, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::Sizing { edge, rect, .. } => (edge, rect.map_or(0, |rect| rect.as_ptr() as usize) as LPARAM),
+            WindowMessage::CaptureChanged { _unused, window, .. } => (_unused, window as LPARAM),
+            WindowMessage::Moving { _unused, rect, .. } => (_unused, rect.map_or(0, |rect| rect.as_ptr() as usize) as LPARAM),
+            WindowMessage::PowerBroadcast { event, data, .. } => (event as usize, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::MdiCreate { _unused, data, .. } => (_unused, data.map_or(0, |data| data.as_ptr() as usize) as LPARAM),
+            WindowMessage::MdiActivate { deactivated, activated, .. } => (deactivated as usize, activated as LPARAM),
+            WindowMessage::NextDialogCtl { target, by_handle, .. } => (target, by_handle),
+            WindowMessage::SpoolerStatus { status, jobs, .. } => (status, jobs),
+            WindowMessage::MenuDrag { position, menu, .. } => (position, menu as LPARAM),
+            WindowMessage::MenuCommand { index, menu, .. } => (index, menu as LPARAM),
+            WindowMessage::MenuGetObject { _unused, info, .. } => (_unused, info.map_or(0, |info| info.as_ptr() as usize) as LPARAM),
+            WindowMessage::GetTitleBarInfoEx { _unused, info, .. } => (_unused, info.map_or(0, |info| info.as_ptr() as usize) as LPARAM),
+            WindowMessage::EnterMenuLoop { track_popup, _unused, .. } => (track_popup, _unused),
+            WindowMessage::ExitMenuLoop { track_popup, _unused, .. } => (track_popup, _unused),
+            WindowMessage::PaintClipboard { viewer, paint, .. } => (viewer as usize, paint as LPARAM),
+            WindowMessage::AskCbFormatName { capacity, buffer, .. } => (capacity, buffer.map_or(0, |buffer| buffer.as_ptr() as usize) as LPARAM),
+            WindowMessage::Help { _unused, info, .. } => (_unused, info.map_or(0, |info| info.as_ptr() as usize) as LPARAM),
+            WindowMessage::ChangeUiState { action, flags, _unused, _unused2, .. } => (action as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, _unused2),
+            WindowMessage::UpdateUiState { action, flags, _unused, _unused2, .. } => (action as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, _unused2),
+            WindowMessage::ContextMenu { window, pos, .. } => (window as usize, pos.into_lparam()),
+            WindowMessage::MdiGetActive { _unused, maximized, .. } => (_unused, maximized.map_or(0, |maximized| maximized.as_ptr() as usize) as LPARAM),
+            WindowMessage::DropFiles { handle, _unused, .. } => (handle as usize, _unused),
+            WindowMessage::Touch { count, _unused, _unused2, handle, .. } => (count as usize | (_unused as usize) << 16 | ((_unused2 as u64) << 32) as usize, handle as LPARAM),
+            WindowMessage::NcPointerUpdate { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::NcPointerDown { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::NcPointerUp { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::PointerUpdate { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::POINTERDOWN { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::POINTERUP { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::POINTERENTER { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::POINTERLEAVE { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::PointerActivate { id, hit_test, _unused, top_window, .. } => (id as usize | (hit_test as usize) << 16 | ((_unused as u64) << 32) as usize, top_window as LPARAM),
+            WindowMessage::PointerCaptureChanged { id, flags, _unused, window, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, window as LPARAM),
+            WindowMessage::PointerWheel { id, delta, _unused, pos, .. } => (id as usize | (delta as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::PointerHWheel { id, delta, _unused, pos, .. } => (id as usize | (delta as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::PointerRoutedTo { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::PointerRoutedAway { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::PointerRoutedReleased { id, flags, _unused, pos, .. } => (id as usize | (flags as usize) << 16 | ((_unused as u64) << 32) as usize, pos.into_lparam()),
+            WindowMessage::ImeSetContext { active, _unused, display_options, .. } => (active as u32 as usize | ((_unused as u64) << 32) as usize, display_options),
+            WindowMessage::ImeNotify { command, data, .. } => (command, data),
+            WindowMessage::MouseHover { modifiers, pos, .. } => (modifiers, pos.into_lparam()),
+            WindowMessage::WtsSessionChange { event, session_id, .. } => (event, session_id),
+            WindowMessage::DpiChanged { dpi_x, dpi_y, _unused, suggested_rect, .. } => (dpi_x as usize | (dpi_y as usize) << 16 | ((_unused as u64) << 32) as usize, suggested_rect.map_or(0, |suggested_rect| suggested_rect.as_ptr() as usize) as LPARAM),
+            WindowMessage::GetDpiScaledSize { dpi, size, .. } => (dpi, size.map_or(0, |size| size.as_ptr() as usize) as LPARAM),
+            WindowMessage::PaletteIsChanging { origin, _unused, .. } => (origin as usize, _unused),
+            WindowMessage::PaletteChanged { origin, _unused, .. } => (origin as usize, _unused),
+            WindowMessage::DwmNcRenderingChanged { rendering_enabled, _unused, .. } => (rendering_enabled, _unused),
+            WindowMessage::Command { id, notification, _unused, control, .. } => (id as usize | (notification as usize) << 16 | ((_unused as u64) << 32) as usize, control as LPARAM),
+            WindowMessage::CtlColorMsgBox { dc, control, .. } => (dc as usize, control as LPARAM),
+            WindowMessage::CtlColorEdit { dc, control, .. } => (dc as usize, control as LPARAM),
+            WindowMessage::CtlColorListBox { dc, control, .. } => (dc as usize, control as LPARAM),
+            WindowMessage::CtlColorBtn { dc, control, .. } => (dc as usize, control as LPARAM),
+            WindowMessage::CtlColorDlg { dc, control, .. } => (dc as usize, control as LPARAM),
+            WindowMessage::CtlColorScrollbar { dc, control, .. } => (dc as usize, control as LPARAM),
+            WindowMessage::CtlColorStatic { dc, control, .. } => (dc as usize, control as LPARAM),
+            WindowMessage::DwmColorizationColorChanged { color, opaque_blend, .. } => (color, opaque_blend),
+            WindowMessage::DwmWindowMaximizedChange { maximized, _unused, .. } => (maximized, _unused),
+            WindowMessage::DwmSendIconIcThumbnail { _unused, size, .. } => (_unused, size),
+            WindowMessage::Null
+            | WindowMessage::Destroy
+            | WindowMessage::KillFocus
+            | WindowMessage::Enable
+            | WindowMessage::SetRedraw
+            | WindowMessage::SetText
+            | WindowMessage::GetText
+            | WindowMessage::GetTextLength
+            | WindowMessage::Paint
+            | WindowMessage::Close
+            | WindowMessage::QueryOpen
+            | WindowMessage::Quit
+            | WindowMessage::SysColorChange
+            | WindowMessage::SettingChange
+            | WindowMessage::DevModeChange
+            | WindowMessage::FontChange
+            | WindowMessage::TimeChange
+            | WindowMessage::CancelMode
+            | WindowMessage::ChildActivate
+            | WindowMessage::QueueSync
+            | WindowMessage::PaintIcon
+            | WindowMessage::IconEraseBackground
+            | WindowMessage::DrawItem
+            | WindowMessage::MeasureItem
+            | WindowMessage::DeleteItem
+            | WindowMessage::GetFont
+            | WindowMessage::SetHotkey
+            | WindowMessage::GetHotkey
+            | WindowMessage::QueryDragIcon
+            | WindowMessage::CompareItem
+            | WindowMessage::CancelJournal
+            | WindowMessage::Notify
+            | WindowMessage::UserChanged
+            | WindowMessage::NotifyFormat
+            | WindowMessage::DisplayChange
+            | WindowMessage::NcCreate
+            | WindowMessage::NcDestroy
+            | WindowMessage::SyncPaint
+            | WindowMessage::ImeStartComposition
+            | WindowMessage::ImeEndComposition
+            | WindowMessage::ImeComposition
+            | WindowMessage::InitDialog
+            | WindowMessage::HScroll
+            | WindowMessage::VScroll
+            | WindowMessage::InitMenu
+            | WindowMessage::InitMenuPopup
+            | WindowMessage::MenuChar
+            | WindowMessage::MenuRButtonUp
+            | WindowMessage::UninitMenuPopup
+            | WindowMessage::QueryUiState
+            | WindowMessage::DeviceChange
+            | WindowMessage::MdiDestroy
+            | WindowMessage::MdiRestore
+            | WindowMessage::MdiNext
+            | WindowMessage::MdiMaximize
+            | WindowMessage::MdiTile
+            | WindowMessage::MdiCascade
+            | WindowMessage::MdiIconArrange
+            | WindowMessage::MdiSetMenu
+            | WindowMessage::EnterSizeMove
+            | WindowMessage::ExitSizeMove
+            | WindowMessage::MdiRefreshMenu
+            | WindowMessage::PointerDeviceChange
+            | WindowMessage::PointerDeviceInRange
+            | WindowMessage::PointerDeviceOutOfRange
+            | WindowMessage::TouchHitTesting
+            | WindowMessage::ImeControl
+            | WindowMessage::ImeCompositionFull
+            | WindowMessage::ImeSelect
+            | WindowMessage::ImeChar
+            | WindowMessage::ImeRequest
+            | WindowMessage::ImeKeydown
+            | WindowMessage::ImeKeyup
+            | WindowMessage::MouseLeave
+            | WindowMessage::NcMouseHover
+            | WindowMessage::NcMouseLeave
+            | WindowMessage::TabletFirst
+            | WindowMessage::TabletLast
+            | WindowMessage::DpiChangedBeforeParent
+            | WindowMessage::DpiChangedAfterParent
+            | WindowMessage::Cut
+            | WindowMessage::Copy
+            | WindowMessage::Paste
+            | WindowMessage::Clear
+            | WindowMessage::Undo
+            | WindowMessage::RenderFormat
+            | WindowMessage::RenderAllFormats
+            | WindowMessage::DestroyClipboard
+            | WindowMessage::DrawClipboard
+            | WindowMessage::VScrollClipboard
+            | WindowMessage::SizeClipboard
+            | WindowMessage::ChangeCbChain
+            | WindowMessage::HScrollClipboard
+            | WindowMessage::QueryNewPalette
+            | WindowMessage::Hotkey
+            | WindowMessage::Print
+            | WindowMessage::PrintClient
+            | WindowMessage::ThemeChanged
+            | WindowMessage::ClipboardUpdate
+            | WindowMessage::DwmCompositionChanged
+            | WindowMessage::DwmSendIconIcLivePreviewBitmap
+            | WindowMessage::HandHeldFirst
+            | WindowMessage::HandHeldLast
+            | WindowMessage::AfxFirst
+            | WindowMessage::AfxLast
+            | WindowMessage::PenWinFirst
+            | WindowMessage::PenWinLast => (0, 0)
+        };
+        RawEvent { msg: self.id(), w_param, l_param }
+    }
+
+    pub fn default_return_semantics(&self) -> ReturnSemantics {
+        match self {
+            WindowMessage::QueryEndSession { .. }
+            | WindowMessage::QueryOpen
+            | WindowMessage::EraseBackground { .. }
+            | WindowMessage::SetCursor { .. }
+            | WindowMessage::NcCreate
+            | WindowMessage::NcActivate { .. }
+            | WindowMessage::InitDialog
+            | WindowMessage::Sizing { .. }
+            | WindowMessage::Moving { .. }
+            | WindowMessage::CopyData { .. }
+            | WindowMessage::PowerBroadcast { .. }
+            | WindowMessage::DeviceChange
+            | WindowMessage::QueryNewPalette
+            | WindowMessage::AppCommand { .. }
+            | WindowMessage::UniChar { .. }
+            | WindowMessage::GetDpiScaledSize { .. } => ReturnSemantics::BoolResult,
+            WindowMessage::NcHitTest { .. } => ReturnSemantics::HitTestCode,
+            WindowMessage::CtlColorMsgBox { .. }
+            | WindowMessage::CtlColorEdit { .. }
+            | WindowMessage::CtlColorListBox { .. }
+            | WindowMessage::CtlColorBtn { .. }
+            | WindowMessage::CtlColorDlg { .. }
+            | WindowMessage::CtlColorScrollbar { .. }
+            | WindowMessage::CtlColorStatic { .. } => ReturnSemantics::BrushHandle,
+            WindowMessage::GetFont
+            | WindowMessage::GetIcon { .. }
+            | WindowMessage::SetIcon { .. }
+            | WindowMessage::QueryDragIcon
+            | WindowMessage::MdiCreate { .. }
+            | WindowMessage::MdiGetActive { .. } => ReturnSemantics::Handle,
+            WindowMessage::GetText
+            | WindowMessage::GetTextLength
+            | WindowMessage::GetDlgCode { .. }
+            | WindowMessage::GetHotkey
+            | WindowMessage::MouseActivate { .. }
+            | WindowMessage::PointerActivate { .. }
+            | WindowMessage::MenuChar
+            | WindowMessage::MenuDrag { .. }
+            | WindowMessage::MenuGetObject { .. }
+            | WindowMessage::CharToItem { .. }
+            | WindowMessage::VKeyToItem { .. }
+            | WindowMessage::CompareItem
+            | WindowMessage::NcCalcSize { .. }
+            | WindowMessage::GetObject { .. }
+            | WindowMessage::NotifyFormat
+            | WindowMessage::Power { .. }
+            | WindowMessage::QueryUiState => ReturnSemantics::Value,
+            _ => ReturnSemantics::ZeroIfHandled
+        }
+    }
+
+    /// Whether the handler's `LRESULT` carries meaning, as opposed to zero once handled.
+    pub fn expects_reply(&self) -> bool {
+        self.default_return_semantics() != ReturnSemantics::ZeroIfHandled
+    }
+
+    /// `ContextMenu` reports `(-1, -1)` when opened from the keyboard.
+    pub fn pos(&self) -> Option<MousePos> {
+        self.pos_in_space().map(|(pos, _)| pos)
+    }
+
+    pub fn pos_in_space(&self) -> Option<(MousePos, CoordSpace)> {
+        match *self {
+            WindowMessage::MouseMove { pos, .. }
+            | WindowMessage::MouseHover { pos, .. }
+            | WindowMessage::LButtonDown { pos, .. }
+            | WindowMessage::LButtonUp { pos, .. }
+            | WindowMessage::LButtonDblClk { pos, .. }
+            | WindowMessage::RButtonDown { pos, .. }
+            | WindowMessage::RButtonUp { pos, .. }
+            | WindowMessage::RButtonDblClk { pos, .. }
+            | WindowMessage::MButtonDown { pos, .. }
+            | WindowMessage::MButtonUp { pos, .. }
+            | WindowMessage::MButtonDblClk { pos, .. }
+            | WindowMessage::XButtonDown { pos, .. }
+            | WindowMessage::XButtonUp { pos, .. }
+            | WindowMessage::XButtonDblClk { pos, .. } => Some((pos, CoordSpace::Client)),
+            WindowMessage::MouseWheel { pos, .. }
+            | WindowMessage::MouseHWheel { pos, .. }
+            | WindowMessage::NcHitTest { pos, .. }
+            | WindowMessage::NcMouseMove { pos, .. }
+            | WindowMessage::NclButtonDown { pos, .. }
+            | WindowMessage::NclButtonUp { pos, .. }
+            | WindowMessage::NclButtonDblClk { pos, .. }
+            | WindowMessage::NcRButtonDown { pos, .. }
+            | WindowMessage::NcRButtonUp { pos, .. }
+            | WindowMessage::NcRButtonDblClk { pos, .. }
+            | WindowMessage::NcMButtonDown { pos, .. }
+            | WindowMessage::NcMButtonUp { pos, .. }
+            | WindowMessage::NcMButtonDblClk { pos, .. }
+            | WindowMessage::NcXButtonDown { pos, .. }
+            | WindowMessage::NcXButtonUp { pos, .. }
+            | WindowMessage::NcXButtonDblClk { pos, .. }
+            | WindowMessage::ContextMenu { pos, .. }
+            | WindowMessage::NcPointerUpdate { pos, .. }
+            | WindowMessage::NcPointerDown { pos, .. }
+            | WindowMessage::NcPointerUp { pos, .. }
+            | WindowMessage::PointerUpdate { pos, .. }
+            | WindowMessage::POINTERDOWN { pos, .. }
+            | WindowMessage::POINTERUP { pos, .. }
+            | WindowMessage::POINTERENTER { pos, .. }
+            | WindowMessage::POINTERLEAVE { pos, .. }
+            | WindowMessage::PointerWheel { pos, .. }
+            | WindowMessage::PointerHWheel { pos, .. }
+            | WindowMessage::PointerRoutedTo { pos, .. }
+            | WindowMessage::PointerRoutedAway { pos, .. }
+            | WindowMessage::PointerRoutedReleased { pos, .. } => Some((pos, CoordSpace::Screen)),
+            _ => None
+        }
+    }
+
+    /// The other window a message refers to, such as the one losing activation or focus, the
+    /// control or child, or the sender. `MdiActivate` gives the child being activated. `None`
+    /// for a null handle.
+    pub fn hwnd_payload(&self) -> Option<HWND> {
+        let window = match *self {
+            WindowMessage::SetFocus { window } => window?.as_ptr() as HWND,
+            WindowMessage::NextDialogCtl { .. } => match self.as_next_dlg_ctl()? {
+                NextDlgCtl::Focus(window) => window,
+                NextDlgCtl::Direction { .. } => return None
+            },
+            WindowMessage::ParentNotify { .. } => match self.as_parent_notify()? {
+                ParentNotifyMessage::ChildCreated { window, .. }
+                | ParentNotifyMessage::ChildDestroyed { window, .. } => window,
+                ParentNotifyMessage::Mouse { .. } => return None
+            },
+            WindowMessage::Activate { window, .. }
+            | WindowMessage::SetCursor { window, .. }
+            | WindowMessage::MouseActivate { top_window: window, .. }
+            | WindowMessage::VKeyToItem { list: window, .. }
+            | WindowMessage::CharToItem { list: window, .. }
+            | WindowMessage::CopyData { sender: window, .. }
+            | WindowMessage::ContextMenu { window, .. }
+            | WindowMessage::EnterIdle { window, .. }
+            | WindowMessage::CaptureChanged { window, .. }
+            | WindowMessage::MdiActivate { activated: window, .. }
+            | WindowMessage::PointerActivate { top_window: window, .. }
+            | WindowMessage::PointerCaptureChanged { window, .. }
+            | WindowMessage::PaintClipboard { viewer: window, .. }
+            | WindowMessage::PaletteIsChanging { origin: window, .. }
+            | WindowMessage::PaletteChanged { origin: window, .. }
+            | WindowMessage::AppCommand { window, .. }
+            | WindowMessage::Command { control: window, .. }
+            | WindowMessage::CtlColorMsgBox { control: window, .. }
+            | WindowMessage::CtlColorEdit { control: window, .. }
+            | WindowMessage::CtlColorListBox { control: window, .. }
+            | WindowMessage::CtlColorBtn { control: window, .. }
+            | WindowMessage::CtlColorDlg { control: window, .. }
+            | WindowMessage::CtlColorScrollbar { control: window, .. }
+            | WindowMessage::CtlColorStatic { control: window, .. } => window,
+            _ => return None
+        };
+        (!window.is_null()).then_some(window)
+    }
+
+    pub fn as_key(&self) -> Option<KeyMessage> {
+        match *self {
+            WindowMessage::SysKeyUp { key_code, info } => Some(KeyMessage { code: key_code, info, sys: true, up: true }),
+            WindowMessage::KeyUp { key_code, info } => Some(KeyMessage { code: key_code, info, sys: false, up: true }),
+            WindowMessage::SysKeyDown { key_code, info } => Some(KeyMessage { code: key_code, info, sys: true, up: false }),
+            WindowMessage::KeyDown { key_code, info } => Some(KeyMessage { code: key_code, info, sys: false, up: false }),
+            _ => None
+        }
+    }
+
+    pub fn as_char(&self) -> Option<CharMessage> {
+        match *self {
+            WindowMessage::Char { code_unit, info } => Some(CharMessage { code_unit: code_unit as u16, info, sys: false, dead: false }),
+            WindowMessage::DeadChar { code_unit, info } => Some(CharMessage { code_unit: code_unit as u16, info, sys: false, dead: true }),
+            WindowMessage::SysChar { code_unit, info } => Some(CharMessage { code_unit: code_unit as u16, info, sys: true, dead: false }),
+            WindowMessage::SysDeadChar { code_unit, info } => Some(CharMessage { code_unit: code_unit as u16, info, sys: true, dead: true }),
+            _ => None
+        }
+    }
+
+    /// `None` for a code point that is not a valid `char`.
+    pub fn as_uni_char(&self) -> Option<UniCharMessage> {
+        const NOCHAR: WPARAM = UNICODE_NOCHAR as _;
+        match *self {
+            WindowMessage::UniChar { code_point: NOCHAR, .. } => Some(UniCharMessage::Probe),
+            WindowMessage::UniChar { code_point, .. } => char::from_u32(code_point as u32).map(UniCharMessage::Char),
+            _ => None
+        }
+    }
+
+    pub fn as_mouse_button(&self) -> Option<MouseButtonMessage> {
+        match *self {
+            WindowMessage::LButtonDown { pos, modifiers } => Some(MouseButtonMessage {
+                action: MouseButtonAction::Down,
+                button: MouseButton::Left,
+                pos,
+                modifiers,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::LButtonUp { pos, modifiers } => Some(MouseButtonMessage {
+                action: MouseButtonAction::Up,
+                button: MouseButton::Left,
+                pos,
+                modifiers,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::LButtonDblClk { pos, modifiers } => Some(MouseButtonMessage {
+                action: MouseButtonAction::DoubleClick,
+                button: MouseButton::Left,
+                pos,
+                modifiers,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::RButtonDown { pos, modifiers } => Some(MouseButtonMessage {
+                action: MouseButtonAction::Down,
+                button: MouseButton::Right,
+                pos,
+                modifiers,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::RButtonUp { pos, modifiers } => Some(MouseButtonMessage {
+                action: MouseButtonAction::Up,
+                button: MouseButton::Right,
+                pos,
+                modifiers,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::RButtonDblClk { pos, modifiers } => Some(MouseButtonMessage {
+                action: MouseButtonAction::DoubleClick,
+                button: MouseButton::Right,
+                pos,
+                modifiers,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::MButtonDown { pos, modifiers } => Some(MouseButtonMessage {
+                action: MouseButtonAction::Down,
+                button: MouseButton::Middle,
+                pos,
+                modifiers,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::MButtonUp { pos, modifiers } => Some(MouseButtonMessage {
+                action: MouseButtonAction::Up,
+                button: MouseButton::Middle,
+                pos,
+                modifiers,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::MButtonDblClk { pos, modifiers } => Some(MouseButtonMessage {
+                action: MouseButtonAction::DoubleClick,
+                button: MouseButton::Middle,
+                pos,
+                modifiers,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::XButtonDown { pos, modifiers, button, .. } => Some(MouseButtonMessage {
+                action: MouseButtonAction::Down,
+                button: MouseButton::X(button),
+                pos,
+                modifiers: modifiers as _,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::XButtonUp { pos, modifiers, button, .. } => Some(MouseButtonMessage {
+                action: MouseButtonAction::Up,
+                button: MouseButton::X(button),
+                pos,
+                modifiers: modifiers as _,
+                space: CoordSpace::Client,
+            }),
+            WindowMessage::XButtonDblClk { pos, modifiers, button, .. } => Some(MouseButtonMessage {
+                action: MouseButtonAction::DoubleClick,
+                button: MouseButton::X(button),
+                pos,
+                modifiers: modifiers as _,
+                space: CoordSpace::Client,
+            }),
+            _ => None
+        }
+    }
+
+    /// The payload is only valid while the message is being handled. `None` when the sender
+    /// passed a null buffer with a nonzero length.
+    pub fn as_copy_data(&self) -> Option<CopyDataMessage<'_>> {
+        let WindowMessage::CopyData { sender, data: Some(data) } = *self else {
+            return None
+        };
+        let data = unsafe { data.as_ref() };
+        let bytes = match (data.lpData.is_null(), data.cbData) {
+            (_, 0) => &[][..],
+            (true, _) => return None,
+            (false, len) => unsafe { core::slice::from_raw_parts(data.lpData as *const u8, len as usize) }
+        };
+        Some(CopyDataMessage { sender, tag: data.dwData, data: bytes })
+    }
+
+    pub fn nc_rendering_enabled(&self) -> Option<bool> {
+        match *self {
+            WindowMessage::DwmNcRenderingChanged { rendering_enabled, .. } => Some(rendering_enabled != 0),
+            _ => None
+        }
+    }
+
+    pub fn dwm_maximized(&self) -> Option<bool> {
+        match *self {
+            WindowMessage::DwmWindowMaximizedChange { maximized, .. } => Some(maximized != 0),
+            _ => None
+        }
+    }
+
+    pub fn as_colorization(&self) -> Option<ColorizationMessage> {
+        match *self {
+            WindowMessage::DwmColorizationColorChanged { color, opaque_blend } => Some(ColorizationMessage {
+                color: Argb(color as u32),
+                opaque_blend: opaque_blend != 0,
+            }),
+            _ => None
+        }
+    }
+
+    /// The largest `(width, height)` the bitmap given to `DwmSetIconicThumbnail` may have.
+    pub fn requested_size(&self) -> Option<(u16, u16)> {
+        match *self {
+            WindowMessage::DwmSendIconIcThumbnail { size, .. } => Some(((size >> 16) as u16, size as u16)),
+            _ => None
+        }
+    }
+
+    pub fn sizing_edge(&self) -> Option<SizingEdge> {
+        match *self {
+            WindowMessage::Sizing { edge, .. } => SizingEdge::from_code(edge),
+            _ => None
+        }
+    }
+
+    pub fn as_next_dlg_ctl(&self) -> Option<NextDlgCtl> {
+        match *self {
+            WindowMessage::NextDialogCtl { target, by_handle } if by_handle as WORD != 0 => Some(NextDlgCtl::Focus(target as HWND)),
+            WindowMessage::NextDialogCtl { target, .. } => Some(NextDlgCtl::Direction { previous: target & 1 != 0 }),
+            _ => None
+        }
+    }
+
+    /// Whether the menu loop belongs to `TrackPopupMenu` rather than the menu bar.
+    pub fn is_track_popup(&self) -> Option<bool> {
+        match *self {
+            WindowMessage::EnterMenuLoop { track_popup, .. }
+            | WindowMessage::ExitMenuLoop { track_popup, .. } => Some(track_popup != 0),
+            _ => None
+        }
+    }
+
+    pub fn as_sys_command(&self) -> Option<SysCommandMessage> {
+        const SIZE: WPARAM = SC_SIZE as _;
+        const MOVE: WPARAM = SC_MOVE as _;
+        const MINIMIZE: WPARAM = SC_MINIMIZE as _;
+        const MAXIMIZE: WPARAM = SC_MAXIMIZE as _;
+        const NEXT_WINDOW: WPARAM = SC_NEXTWINDOW as _;
+        const PREV_WINDOW: WPARAM = SC_PREVWINDOW as _;
+        const CLOSE: WPARAM = SC_CLOSE as _;
+        const VSCROLL: WPARAM = SC_VSCROLL as _;
+        const HSCROLL: WPARAM = SC_HSCROLL as _;
+        const MOUSE_MENU: WPARAM = SC_MOUSEMENU as _;
+        const KEY_MENU: WPARAM = SC_KEYMENU as _;
+        const ARRANGE: WPARAM = SC_ARRANGE as _;
+        const RESTORE: WPARAM = SC_RESTORE as _;
+        const TASK_LIST: WPARAM = SC_TASKLIST as _;
+        const SCREEN_SAVE: WPARAM = SC_SCREENSAVE as _;
+        const HOTKEY: WPARAM = SC_HOTKEY as _;
+        const DEFAULT: WPARAM = SC_DEFAULT as _;
+        const MONITOR_POWER: WPARAM = SC_MONITORPOWER as _;
+        const CONTEXT_HELP: WPARAM = SC_CONTEXTHELP as _;
+        let WindowMessage::SysCommand { command, pos } = *self else {
+            return None;
+        };
+        let command = match command & 0xFFF0 {
+            SIZE => SystemCommand::Size,
+            MOVE => SystemCommand::Move,
+            MINIMIZE => SystemCommand::Minimize,
+            MAXIMIZE => SystemCommand::Maximize,
+            NEXT_WINDOW => SystemCommand::NextWindow,
+            PREV_WINDOW => SystemCommand::PrevWindow,
+            CLOSE => SystemCommand::Close,
+            VSCROLL => SystemCommand::VScroll,
+            HSCROLL => SystemCommand::HScroll,
+            MOUSE_MENU => SystemCommand::MouseMenu,
+            KEY_MENU => SystemCommand::KeyMenu {
+                accel_char: char::from_u32(pos.x as u16 as u32).filter(|&c| c != '\0'),
+            },
+            ARRANGE => SystemCommand::Arrange,
+            RESTORE => SystemCommand::Restore,
+            TASK_LIST => SystemCommand::TaskList,
+            SCREEN_SAVE => SystemCommand::ScreenSave,
+            HOTKEY => SystemCommand::Hotkey,
+            DEFAULT => SystemCommand::Default,
+            MONITOR_POWER => SystemCommand::MonitorPower,
+            CONTEXT_HELP => SystemCommand::ContextHelp,
+            _ => SystemCommand::Other(command)
+        };
+        Some(SysCommandMessage { command, pos })
+    }
+
+    pub fn as_menu_command(&self) -> Option<MenuCommandMessage> {
+        match *self {
+            WindowMessage::MenuCommand { index, menu } => Some(MenuCommandMessage { index: index as u32, menu }),
+            _ => None
+        }
+    }
+
+    /// The handler replies with a `MenuDragResult`.
+    pub fn as_menu_drag(&self) -> Option<MenuDragMessage> {
+        match *self {
+            WindowMessage::MenuDrag { position, menu } => Some(MenuDragMessage { position: position as u32, menu }),
+            _ => None
+        }
+    }
+
+    /// `None` for actions other than the three `UIS_*` codes.
+    pub fn as_ui_state(&self) -> Option<UiStateMessage> {
+        match *self {
+            WindowMessage::ChangeUiState { action, flags, .. }
+            | WindowMessage::UpdateUiState { action, flags, .. } => Some(UiStateMessage {
+                action: UiStateAction::from_code(action)?,
+                flags: UiStateFlags(flags),
+            }),
+            _ => None
+        }
+    }
+
+    pub fn help_info(&self) -> Option<HelpInfo> {
+        const MENU: i32 = HELPINFO_MENUITEM as _;
+        const WINDOW: i32 = HELPINFO_WINDOW as _;
+        let WindowMessage::Help { info: Some(info), .. } = *self else {
+            return None;
+        };
+        let info = unsafe { info.as_ref() };
+        Some(HelpInfo {
+            context_type: match info.iContextType {
+                MENU => HelpContext::Menu,
+                WINDOW => HelpContext::Window,
+                _ => return None
+            },
+            ctrl_id: info.iCtrlId,
+            item_handle: info.hItemHandle,
+            pos: MousePos { x: info.MousePos.x as _, y: info.MousePos.y as _ },
+        })
+    }
+
+    pub fn as_input_lang_change_request(&self) -> Option<InputLangChangeRequestMessage> {
+        match *self {
+            WindowMessage::InputLangChangeRequest { flags, layout } => Some(InputLangChangeRequestMessage {
+                flags: InputLangChangeFlags(flags as u32),
+                layout,
+            }),
+            _ => None
+        }
+    }
+
+    pub fn as_input_lang_change(&self) -> Option<InputLangChangeMessage> {
+        match *self {
+            WindowMessage::InputLangChange { charset, layout } => Some(InputLangChangeMessage { charset: charset as u8, layout }),
+            _ => None
+        }
+    }
+
+    pub fn as_training_card(&self) -> Option<TrainingCardMessage> {
+        const OK: WPARAM = IDOK as _;
+        const CANCEL: WPARAM = IDCANCEL as _;
+        const ABORT: WPARAM = IDABORT as _;
+        const RETRY: WPARAM = IDRETRY as _;
+        const IGNORE: WPARAM = IDIGNORE as _;
+        const YES: WPARAM = IDYES as _;
+        const NO: WPARAM = IDNO as _;
+        const CLOSE: WPARAM = IDCLOSE as _;
+        const HELP: WPARAM = IDHELP as _;
+        const DATA: WPARAM = HELP_TCARD_DATA as _;
+        const OTHER_CALLER: WPARAM = HELP_TCARD_OTHER_CALLER as _;
+        let WindowMessage::TCard { action, data } = *self else {
+            return None;
+        };
+        Some(match action {
+            OK => TrainingCardMessage::Ok,
+            CANCEL => TrainingCardMessage::Cancel,
+            ABORT => TrainingCardMessage::Abort,
+            RETRY => TrainingCardMessage::Retry,
+            IGNORE => TrainingCardMessage::Ignore,
+            YES => TrainingCardMessage::Yes,
+            NO => TrainingCardMessage::No,
+            CLOSE => TrainingCardMessage::Close,
+            HELP => TrainingCardMessage::Help,
+            DATA => TrainingCardMessage::Data(data),
+            OTHER_CALLER => TrainingCardMessage::OtherCaller,
+            id => TrainingCardMessage::Custom { id, data }
+        })
+    }
+
+    /// The handler writes the name of the owner-display format it put on the clipboard.
+    pub fn as_ask_cb_format_name(&self) -> Option<AskCbFormatNameMessage> {
+        match *self {
+            WindowMessage::AskCbFormatName { capacity, buffer: Some(buffer) } => Some(AskCbFormatNameMessage { capacity, buffer }),
+            _ => None
+        }
+    }
+
+    pub fn as_paint_clipboard(&self) -> Option<PaintClipboardMessage> {
+        match *self {
+            WindowMessage::PaintClipboard { viewer, paint } => Some(PaintClipboardMessage { viewer, paint }),
+            _ => None
+        }
+    }
+
+    pub fn title_bar_info(&mut self) -> Option<TitleBarInfo<'_>> {
+        match *self {
+            WindowMessage::GetTitleBarInfoEx { info: Some(mut info), .. } => Some(TitleBarInfo(unsafe { info.as_mut() })),
+            _ => None
+        }
+    }
+
+    /// Describes the drop position; the handler fills in `pvObj` and replies with
+    /// `MNGO_NOERROR` or `MNGO_NOINTERFACE`.
+    pub fn menu_get_object(&mut self) -> Option<&mut MENUGETOBJECTINFO> {
+        match *self {
+            WindowMessage::MenuGetObject { info: Some(mut info), .. } => Some(unsafe { info.as_mut() }),
+            _ => None
+        }
+    }
+
+    /// The screen rectangle being dragged by `Sizing` or `Moving`. The handler may adjust it
+    /// and returns `TRUE` when it did.
+    pub fn drag_rect(&mut self) -> Option<&mut RECT> {
+        match *self {
+            WindowMessage::Sizing { rect: Some(mut rect), .. }
+            | WindowMessage::Moving { rect: Some(mut rect), .. } => Some(unsafe { rect.as_mut() }),
+            _ => None
+        }
+    }
+
+    /// The share of CPU time spent compacting memory, from 0 to 1; high values mean the
+    /// system is short on memory.
+    pub fn compacting_ratio(&self) -> Option<f32> {
+        match *self {
+            WindowMessage::Compacting { ratio, .. } => Some(ratio as f32 / 0xFFFF as f32),
+            _ => None
+        }
+    }
+
+    pub fn as_comm_notify(&self) -> Option<CommNotifyMessage> {
+        match *self {
+            WindowMessage::CommNotify { device_id, events } => Some(CommNotifyMessage {
+                device_id: device_id as u16,
+                events: CommEvents(events as u16),
+            }),
+            _ => None
+        }
+    }
+
+    pub fn as_spooler_status(&self) -> Option<SpoolerStatusMessage> {
+        match *self {
+            WindowMessage::SpoolerStatus { status, jobs } => Some(SpoolerStatusMessage {
+                status: status as u32,
+                jobs_remaining: jobs as u16,
+            }),
+            _ => None
+        }
+    }
+
+    /// `None` for codes other than the three `LegacyPowerCode`s.
+    pub fn as_power(&self) -> Option<PowerMessage> {
+        match *self {
+            WindowMessage::Power { code, .. } => LegacyPowerCode::from_code(code).map(|code| PowerMessage { code }),
+            _ => None
+        }
+    }
+
+    pub fn as_set_font(&self) -> Option<SetFontMessage> {
+        match *self {
+            WindowMessage::SetFont { font, redraw } => Some(SetFontMessage { font, redraw: redraw as WORD != 0 }),
+            _ => None
+        }
+    }
+
+    pub fn as_erase_background(&self) -> Option<EraseBackgroundMessage> {
+        match *self {
+            WindowMessage::EraseBackground { dc, .. } => Some(EraseBackgroundMessage { dc }),
+            _ => None
+        }
+    }
+
+    /// `None` when the capture was released rather than taken. Calling `SetCapture` from
+    /// the handler re-enters the capture change and must be avoided.
+    pub fn capture_gained(&self) -> Option<HWND> {
+        match *self {
+            WindowMessage::CaptureChanged { window, .. } if !window.is_null() => Some(window),
+            _ => None
+        }
+    }
+
+    pub fn as_parent_notify(&self) -> Option<ParentNotifyMessage> {
+        const CREATE: WORD = WM_CREATE as _;
+        const DESTROY: WORD = WM_DESTROY as _;
+        const L_BUTTON_DOWN: WORD = WM_LBUTTONDOWN as _;
+        const M_BUTTON_DOWN: WORD = WM_MBUTTONDOWN as _;
+        const R_BUTTON_DOWN: WORD = WM_RBUTTONDOWN as _;
+        const X_BUTTON_DOWN: WORD = WM_XBUTTONDOWN as _;
+        let WindowMessage::ParentNotify { event, id, data, .. } = *self else {
+            return None
+        };
+        let pos = MousePos::from_lparam(data);
+        Some(match event {
+            CREATE => ParentNotifyMessage::ChildCreated { id, window: data as HWND },
+            DESTROY => ParentNotifyMessage::ChildDestroyed { id, window: data as HWND },
+            L_BUTTON_DOWN => ParentNotifyMessage::Mouse { button: MouseButton::Left, pos },
+            M_BUTTON_DOWN => ParentNotifyMessage::Mouse { button: MouseButton::Middle, pos },
+            R_BUTTON_DOWN => ParentNotifyMessage::Mouse { button: MouseButton::Right, pos },
+            X_BUTTON_DOWN => ParentNotifyMessage::Mouse { button: MouseButton::X(id), pos },
+            _ => return None
+        })
+    }
+
+    pub fn as_mouse_wheel(&self) -> Option<MouseWheelMessage> {
+        let (axis, modifiers, delta, pos) = match *self {
+            WindowMessage::MouseWheel { modifiers, delta, pos, .. } => (WheelAxis::Vertical, modifiers, delta, pos),
+            WindowMessage::MouseHWheel { modifiers, delta, pos, .. } => (WheelAxis::Horizontal, modifiers, delta, pos),
+            _ => return None
+        };
+        Some(MouseWheelMessage {
+            axis,
+            delta: delta as i16,
+            modifiers: Modifiers(modifiers as WPARAM),
+            pos,
+            space: CoordSpace::Screen,
+        })
+    }
+
+    pub fn as_nc_mouse_button(&self) -> Option<NcMouseButtonMessage> {
+        match *self {
+            WindowMessage::NclButtonDown { hit_test, pos } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::Down,
+                button: MouseButton::Left,
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NclButtonUp { hit_test, pos } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::Up,
+                button: MouseButton::Left,
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NclButtonDblClk { hit_test, pos } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::DoubleClick,
+                button: MouseButton::Left,
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NcRButtonDown { hit_test, pos } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::Down,
+                button: MouseButton::Right,
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NcRButtonUp { hit_test, pos } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::Up,
+                button: MouseButton::Right,
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NcRButtonDblClk { hit_test, pos } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::DoubleClick,
+                button: MouseButton::Right,
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NcMButtonDown { hit_test, pos } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::Down,
+                button: MouseButton::Middle,
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NcMButtonUp { hit_test, pos } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::Up,
+                button: MouseButton::Middle,
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NcMButtonDblClk { hit_test, pos } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::DoubleClick,
+                button: MouseButton::Middle,
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NcXButtonDown { hit_test, button, pos, .. } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::Down,
+                button: MouseButton::X(button),
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NcXButtonUp { hit_test, button, pos, .. } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::Up,
+                button: MouseButton::X(button),
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            WindowMessage::NcXButtonDblClk { hit_test, button, pos, .. } => Some(NcMouseButtonMessage {
+                action: MouseButtonAction::DoubleClick,
+                button: MouseButton::X(button),
+                hit_test: HitTest::from_code(hit_test as i16),
+                pos,
+                space: CoordSpace::Screen,
+            }),
+            _ => None
+        }
+    }
+
+    pub fn nc_hit_test(&self) -> Option<HitTest> {
+        match *self {
+            WindowMessage::NcMouseMove { hit_test, .. } => HitTest::from_code(hit_test as i16),
+            _ => self.as_nc_mouse_button().and_then(|button| button.hit_test)
+        }
+    }
+
+    /// `HitTest::classify` applied to an `NcHitTest` position.
+    pub fn default_hit_test(&self, window_rect: &RECT, border: i32, caption_height: i32) -> Option<HitTest> {
+        match *self {
+            WindowMessage::NcHitTest { pos, .. } => Some(HitTest::classify(pos, window_rect, border, caption_height)),
+            _ => None
+        }
+    }
+
+    pub fn as_menu_select(&self) -> Option<MenuSelectMessage> {
+        match *self {
+            WindowMessage::MenuSelect { flags: 0xFFFF, menu, .. } if menu.is_null() => Some(MenuSelectMessage {
+                flags: MenuFlags(0),
+                item: MenuItem::Closed,
+                menu,
+            }),
+            WindowMessage::MenuSelect { item, flags, menu, .. } => {
+                let flags = MenuFlags(flags);
+                let item = if flags.popup() { MenuItem::Popup(item) } else { MenuItem::Command(item) };
+                Some(MenuSelectMessage { flags, item, menu })
+            },
+            _ => None
+        }
+    }
+
+    pub fn as_timer(&self) -> Option<TimerMessage> {
+        match *self {
+            WindowMessage::TIMER { id, callback } => Some(TimerMessage { id, callback }),
+            _ => None
+        }
+    }
+
+    pub fn input_code(&self) -> Option<RawInputCode> {
+        match *self {
+            WindowMessage::Input { code, .. } => match GET_RAWINPUT_CODE_WPARAM(code) {
+                RIM_INPUT => Some(RawInputCode::Foreground),
+                RIM_INPUTSINK => Some(RawInputCode::Sink),
+                _ => None
+            },
+            _ => None
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn input_data(&self) -> Option<RawInput> {
+        const RI_MOUSE_HWHEEL: u16 = 0x0800;
+        let handle = match *self {
+            WindowMessage::Input { handle, .. } => handle,
+            _ => return None
+        };
+        let header_size = size_of::<RAWINPUTHEADER>() as UINT;
+        let mut input: RAWINPUT = unsafe { core::mem::zeroed() };
+        let mut size = size_of::<RAWINPUT>() as UINT;
+        let read = unsafe {
+            GetRawInputData(handle, RID_INPUT, &mut input as *mut RAWINPUT as _, &mut size, header_size)
+        };
+        if read == UINT::MAX {
+            // HID reports don't fit in a RAWINPUT, only the header is of interest then
+            let mut header: RAWINPUTHEADER = unsafe { core::mem::zeroed() };
+            size = header_size;
+            let read = unsafe {
+                GetRawInputData(handle, RID_HEADER, &mut header as *mut RAWINPUTHEADER as _, &mut size, header_size)
+            };
+            return match (read, header.dwType) {
+                (UINT::MAX, _) => None,
+                (_, RIM_TYPEHID) => Some(RawInput::Hid { device: header.hDevice }),
+                _ => None
+            };
+        }
+        let device = input.header.hDevice;
+        match input.header.dwType {
+            RIM_TYPEMOUSE => {
+                let mouse = unsafe { raw_mouse(&input) };
+                let (button_flags, button_data) = raw_mouse_buttons(mouse);
+                let wheel = button_flags & (RI_MOUSE_WHEEL | RI_MOUSE_HWHEEL) != 0;
+                Some(RawInput::Mouse(RawInputMouse {
+                    device,
+                    absolute: mouse.usFlags & MOUSE_MOVE_ABSOLUTE != 0,
+                    virtual_desktop: mouse.usFlags & MOUSE_VIRTUAL_DESKTOP != 0,
+                    x: mouse.lLastX,
+                    y: mouse.lLastY,
+                    button_flags,
+                    wheel_delta: if wheel { button_data as i16 } else { 0 },
+                    raw_buttons: mouse.ulRawButtons,
+                }))
+            },
+            RIM_TYPEKEYBOARD => {
+                let keyboard = unsafe { raw_keyboard(&input) };
+                Some(RawInput::Keyboard(RawInputKeyboard {
+                    device,
+                    make_code: keyboard.MakeCode,
+                    flags: keyboard.Flags,
+                    vkey: keyboard.VKey,
+                    message: keyboard.Message,
+                }))
+            },
+            RIM_TYPEHID => Some(RawInput::Hid { device }),
+            _ => None
+        }
+    }
+
+    pub fn as_input_device_change(&self) -> Option<InputDeviceChangeMessage> {
+        match *self {
+            WindowMessage::InputDeviceChange { change, device } => match change as DWORD {
+                GIDC_ARRIVAL => Some(InputDeviceChangeMessage { arrived: true, device }),
+                GIDC_REMOVAL => Some(InputDeviceChangeMessage { arrived: false, device }),
+                _ => None
+            },
+            _ => None
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// The touch handle is closed once the returned iterator is dropped, so the message
+    /// must not be forwarded to `DefWindowProc` afterwards.
+    pub fn touch_points(&self) -> Option<TouchPoints> {
+        match *self {
+            WindowMessage::Touch { count, handle, .. } => {
+                let mut points = vec![unsafe { core::mem::zeroed::<TOUCHINPUT>() }; count as usize];
+                let ok = unsafe {
+                    GetTouchInputInfo(handle, count as UINT, points.as_mut_ptr(), size_of::<TOUCHINPUT>() as _)
+                };
+                if ok == FALSE {
+                    return None;
+                }
+                Some(TouchPoints { handle, points, next: 0 })
+            },
+            _ => None
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// Client coordinates of the drop and whether it landed in the client area. Only reads
+    /// the handle, which `dropped_files` still has to release; call it before that guard
+    /// is dropped.
+    pub fn drop_point(&self) -> Option<(MousePos, bool)> {
+        match *self {
+            WindowMessage::DropFiles { handle, .. } => {
+                let mut point: POINT = unsafe { core::mem::zeroed() };
+                let in_client = unsafe { DragQueryPoint(handle, &mut point) };
+                Some((MousePos { x: point.x as i16, y: point.y as i16 }, in_client != FALSE))
+            },
+            _ => None
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// The drop handle is released with `DragFinish` once the returned iterator is dropped,
+    /// so this must be called at most once per message.
+    pub fn dropped_files(&self) -> Option<DroppedFiles> {
+        match *self {
+            WindowMessage::DropFiles { handle, .. } => {
+                let count = unsafe { DragQueryFileW(handle, UINT::MAX, null_mut(), 0) };
+                Some(DroppedFiles { handle, count, next: 0 })
+            },
+            _ => None
+        }
+    }
+
+    pub fn as_activate_app(&self) -> Option<ActivateAppMessage> {
+        match *self {
+            WindowMessage::ActivateApp { activated, thread_id, .. } => Some(ActivateAppMessage {
+                activated: activated != FALSE,
+                thread_id,
+            }),
+            _ => None
+        }
+    }
+
+    /// The window that realized its palette; skip handling when it is the receiving window,
+    /// or realizing again loops forever. `QueryNewPalette` itself replies `TRUE` when it
+    /// realized a palette.
+    pub fn palette_origin(&self) -> Option<HWND> {
+        match *self {
+            WindowMessage::PaletteIsChanging { origin, .. }
+            | WindowMessage::PaletteChanged { origin, .. } => Some(origin),
+            _ => None
+        }
+    }
+
+    /// The handler replies with an `ItemResult`.
+    pub fn as_vkey_to_item(&self) -> Option<VKeyToItemMessage> {
+        match *self {
+            WindowMessage::VKeyToItem { key, caret, list, .. } => Some(VKeyToItemMessage {
+                key: VirtualKey::from_code(key),
+                key_code: key,
+                caret,
+                list,
+            }),
+            _ => None
+        }
+    }
+
+    /// The handler replies with an `ItemResult`.
+    pub fn as_char_to_item(&self) -> Option<CharToItemMessage> {
+        match *self {
+            WindowMessage::CharToItem { character, caret, list, .. } => Some(CharToItemMessage { character, caret, list }),
+            _ => None
+        }
+    }
+
+    pub fn ime_notify_command(&self) -> Option<ImeNotifyCommand> {
+        match *self {
+            WindowMessage::ImeNotify { command, .. } => ImeNotifyCommand::from_code(command as u32),
+            _ => None
+        }
+    }
+
+    pub fn as_ime_set_context(&self) -> Option<ImeSetContextMessage> {
+        match *self {
+            WindowMessage::ImeSetContext { active, display_options, .. } => Some(ImeSetContextMessage {
+                active: active != 0,
+                display_options: ImeDisplayOptions(display_options as u32),
+            }),
+            _ => None
+        }
+    }
+
+    pub fn as_session_change(&self) -> Option<SessionChangeMessage> {
+        match *self {
+            WindowMessage::WtsSessionChange { event, session_id } => Some(SessionChangeMessage {
+                event: SessionEvent::from_code(event)?,
+                session_id: session_id as u32,
+            }),
+            _ => None
+        }
+    }
+
+    /// `reason` is `None` when the change comes from a `ShowWindow` call.
+    pub fn as_show_window(&self) -> Option<ShowWindowMessage> {
+        match *self {
+            WindowMessage::ShowWindow { shown, status, .. } => Some(ShowWindowMessage {
+                shown: shown != 0,
+                reason: WindowShown::from_code(status),
+            }),
+            _ => None
+        }
+    }
+
+    pub fn end_session_reason(&self) -> Option<EndSessionReason> {
+        match *self {
+            WindowMessage::QueryEndSession { reason, .. }
+            | WindowMessage::EndSession { reason, .. } => Some(EndSessionReason(reason as u32)),
+            _ => None
+        }
+    }
+
+    pub fn as_end_session(&self) -> Option<EndSessionMessage> {
+        match *self {
+            WindowMessage::EndSession { ending, reason, .. } => Some(EndSessionMessage {
+                ending: ending != 0,
+                reason: EndSessionReason(reason as u32),
+            }),
+            _ => None
+        }
+    }
+
+    /// `msg` is the message the dialog manager is processing, if any. The handler replies
+    /// with a `DlgCode`.
+    pub fn as_get_dlg_code(&self) -> Option<GetDlgCodeMessage<'_>> {
+        match *self {
+            WindowMessage::GetDlgCode { key, msg } => Some(GetDlgCodeMessage {
+                key: VirtualKey::from_code(key as u16),
+                msg: msg.map(|msg| unsafe { &*msg.as_ptr() }),
+            }),
+            _ => None
+        }
+    }
+
+    pub fn nc_update_region(&self) -> Option<NcUpdateRegion> {
+        match *self {
+            WindowMessage::NcPaint { update_region: 1, .. } => Some(NcUpdateRegion::EntireFrame),
+            WindowMessage::NcPaint { update_region, .. } => Some(NcUpdateRegion::Region(update_region as HRGN)),
+            _ => None
+        }
+    }
+
+    /// When `active` is false the handler returns `TRUE` to proceed or `FALSE` to block deactivation.
+    pub fn as_nc_activate(&self) -> Option<NcActivateMessage> {
+        match *self {
+            WindowMessage::NcActivate { active, update_region, .. } => Some(NcActivateMessage {
+                active: active != 0,
+                update_region: match update_region {
+                    -1 => NcActivateRegion::Suppress,
+                    0 => NcActivateRegion::Entire,
+                    region => NcActivateRegion::Region(region as HRGN),
+                },
+            }),
+            _ => None
+        }
+    }
+
+    /// The handler returns `TRUE` once it has set the cursor, which stops further processing.
+    pub fn as_set_cursor(&self) -> Option<SetCursorMessage> {
+        match *self {
+            WindowMessage::SetCursor { window, hit_test, trigger_message, .. } => Some(SetCursorMessage {
+                window,
+                hit_test: HitTest::from_code(hit_test as i16),
+                trigger_message: trigger_message as u32,
+            }),
+            _ => None
+        }
+    }
+
+    pub fn as_mouse_activate(&self) -> Option<MouseActivateMessage> {
+        match *self {
+            WindowMessage::MouseActivate { top_window, hit_test, trigger_message, .. } => Some(MouseActivateMessage {
+                top_window,
+                hit_test: HitTest::from_code(hit_test as i16),
+                trigger_message,
+            }),
+            _ => None
+        }
+    }
+
+    pub fn as_pointer_activate(&self) -> Option<PointerActivateMessage> {
+        match *self {
+            WindowMessage::PointerActivate { id, hit_test, top_window, .. } => Some(PointerActivateMessage {
+                pointer_id: id,
+                hit_test: HitTest::from_code(hit_test as i16),
+                top_window,
+            }),
+            _ => None
+        }
+    }
+
+    /// The handler fills in `hmenuNext` and `hwndNext` to redirect menu navigation.
+    pub fn next_menu(&mut self) -> Option<(Option<VirtualKey>, &mut MDINEXTMENU)> {
+        match *self {
+            WindowMessage::NextMenu { key, data: Some(mut data) } => Some((VirtualKey::from_code(key as u16), unsafe { data.as_mut() })),
+            _ => None
+        }
+    }
+
+    pub fn as_get_object(&self) -> Option<GetObjectMessage> {
+        match *self {
+            WindowMessage::GetObject { flags, object_id } => Some(GetObjectMessage {
+                object_id: object_id as i32,
+                flags: flags as u32,
+            }),
+            _ => None
+        }
+    }
+
+    fn style_struct(&self) -> Option<(GwlStyle, NonNull<STYLESTRUCT>)> {
+        match *self {
+            WindowMessage::StyleChanging { style, data: Some(data), .. }
+            | WindowMessage::StyleChanged { style, data: Some(data), .. } => Some((style, data)),
+            _ => None
+        }
+    }
+
+    pub fn old_style(&self) -> Option<u32> {
+        self.style_struct().map(|(_, data)| unsafe { data.as_ref() }.styleOld)
+    }
+
+    pub fn new_style(&self) -> Option<u32> {
+        self.style_struct().map(|(_, data)| unsafe { data.as_ref() }.styleNew)
+    }
+
+    /// Only `StyleChanging` lets the new style be replaced; returns false for anything else.
+    pub fn set_new_style(&mut self, style: u32) -> bool {
+        match *self {
+            WindowMessage::StyleChanging { data: Some(mut data), .. } => {
+                unsafe { data.as_mut() }.styleNew = style;
+                true
+            }
+            _ => false
+        }
+    }
+
+    pub fn style_change(&self) -> Option<StyleChange> {
+        let (style, data) = self.style_struct()?;
+        let data = unsafe { data.as_ref() };
+        Some(match style {
+            GwlStyle::Style => StyleChange::Style {
+                old: WindowStyles(data.styleOld),
+                new: WindowStyles(data.styleNew),
+            },
+            GwlStyle::ExStyle => StyleChange::ExStyle {
+                old: ExWindowStyles(data.styleOld),
+                new: ExWindowStyles(data.styleNew),
+            },
+        })
+    }
+
+    pub fn as_dpi_changed(&self) -> Option<DpiChangedMessage> {
+        match *self {
+            WindowMessage::DpiChanged { dpi_x, dpi_y, suggested_rect: Some(rect), .. } => Some(DpiChangedMessage {
+                dpi_x,
+                dpi_y,
+                suggested_rect: unsafe { *rect.as_ptr() },
+            }),
+            _ => None
+        }
+    }
+
+    /// The current size comes in through the `SIZE`; the handler overwrites it with the size
+    /// wanted at the new DPI and returns `TRUE`, or returns `FALSE` to keep linear scaling.
+    pub fn dpi_scaled_size(&mut self) -> Option<(u32, &mut SIZE)> {
+        match *self {
+            WindowMessage::GetDpiScaledSize { dpi, size: Some(mut size) } => Some((dpi as u32, unsafe { size.as_mut() })),
+            _ => None
+        }
+    }
+
+    pub fn as_enter_idle(&self) -> Option<EnterIdleMessage> {
+        const DIALOG: WPARAM = MSGF_DIALOGBOX as _;
+        const MENU: WPARAM = MSGF_MENU as _;
+        match *self {
+            WindowMessage::EnterIdle { reason: DIALOG, window } => Some(EnterIdleMessage { reason: IdleReason::Dialog, window }),
+            WindowMessage::EnterIdle { reason: MENU, window } => Some(EnterIdleMessage { reason: IdleReason::Menu, window }),
+            _ => None
+        }
+    }
+
+    pub fn as_mdi_activate(&self) -> Option<MdiActivateMessage> {
+        match *self {
+            WindowMessage::MdiActivate { deactivated, activated } => Some(MdiActivateMessage { deactivated, activated }),
+            _ => None
+        }
+    }
+
+    pub fn as_app_command(&self) -> Option<AppCommandMessage> {
+        const MASK: WORD = FAPPCOMMAND_MASK as _;
+        const KEY: WORD = FAPPCOMMAND_KEY as _;
+        const MOUSE: WORD = FAPPCOMMAND_MOUSE as _;
+        const OEM: WORD = FAPPCOMMAND_OEM as _;
+        match *self {
+            WindowMessage::AppCommand { window, keys, command, .. } => Some(AppCommandMessage {
+                window,
+                command: AppCommand::from_code(command & !MASK),
+                device: match command & MASK {
+                    KEY => Some(AppCommandDevice::Key),
+                    MOUSE => Some(AppCommandDevice::Mouse),
+                    OEM => Some(AppCommandDevice::Oem),
+                    _ => None
+                },
+                keys: Modifiers(keys as WPARAM),
+            }),
+            _ => None
+        }
+    }
+
+    pub fn as_media_command(&self) -> Option<MediaCommand> {
+        self.as_app_command()?.command.and_then(MediaCommand::from_app_command)
+    }
+
+    pub fn as_gesture_notify(&self) -> Option<GestureNotifyMessage> {
+        match *self {
+            WindowMessage::GestureNotify { info: Some(info), .. } => {
+                let info = unsafe { info.as_ref() };
+                Some(GestureNotifyMessage {
+                    target: info.hwndTarget,
+                    location: MousePos { x: info.ptsLocation.x, y: info.ptsLocation.y },
+                    instance_id: info.dwInstanceID,
+                })
+            },
+            _ => None
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// The gesture handle is closed once the returned info is dropped, so the message must
+    /// not be forwarded to `DefWindowProc` afterwards and this must be called at most once
+    /// per message. On `None` the handle is left open for `DefWindowProc`.
+    pub fn gesture_info(&self) -> Option<GestureInfo> {
+        let handle = match *self {
+            WindowMessage::Gesture { handle, .. } => handle,
+            _ => return None
+        };
+        let mut info: GESTUREINFO = unsafe { core::mem::zeroed() };
+        info.cbSize = size_of::<GESTUREINFO>() as UINT;
+        if unsafe { GetGestureInfo(handle, &mut info) } == FALSE {
+            return None;
+        }
+        let arguments = info.ullArguments;
+        let kind = match info.dwID {
+            GID_BEGIN => GestureKind::Begin,
+            GID_END => GestureKind::End,
+            GID_ZOOM => GestureKind::Zoom { distance: arguments as u32 },
+            GID_PAN => GestureKind::Pan { distance: arguments as u32 },
+            GID_ROTATE => GestureKind::Rotate {
+                angle: (arguments as u32 as f64 / 65535.0) * 4.0 * core::f64::consts::PI - 2.0 * core::f64::consts::PI
+            },
+            GID_TWOFINGERTAP => GestureKind::TwoFingerTap { distance: arguments as u32 },
+            GID_PRESSANDTAP => GestureKind::PressAndTap {
+                dx: arguments as u16 as i16,
+                dy: (arguments >> 16) as u16 as i16
+            },
+            _ => return None
+        };
+        Some(GestureInfo {
+            kind,
+            flags: GestureFlags(info.dwFlags),
+            target: info.hwndTarget,
+            location: MousePos { x: info.ptsLocation.x, y: info.ptsLocation.y },
+            instance_id: info.dwInstanceID,
+            sequence_id: info.dwSequenceID,
+            handle,
+        })
+    }
+
+    pub fn pointer_id(&self) -> Option<u32> {
         match *self {
-            WindowMessage::SysKeyUp { key_code, info } => Some(KeyMessage { code: key_code, info, sys: true, up: true }),
-            WindowMessage::KeyUp { key_code, info } => Some(KeyMessage { code: key_code, info, sys: false, up: true }),
-            WindowMessage::SysKeyDown { key_code, info } => Some(KeyMessage { code: key_code, info, sys: true, up: false }),
-            WindowMessage::KeyDown { key_code, info } => Some(KeyMessage { code: key_code, info, sys: false, up: false }),
+            WindowMessage::NcPointerUpdate { id, .. }
+            | WindowMessage::NcPointerDown { id, .. }
+            | WindowMessage::NcPointerUp { id, .. }
+            | WindowMessage::PointerUpdate { id, .. }
+            | WindowMessage::POINTERDOWN { id, .. }
+            | WindowMessage::POINTERUP { id, .. }
+            | WindowMessage::POINTERENTER { id, .. }
+            | WindowMessage::POINTERLEAVE { id, .. }
+            | WindowMessage::PointerRoutedTo { id, .. }
+            | WindowMessage::PointerRoutedAway { id, .. }
+            | WindowMessage::PointerRoutedReleased { id, .. }
+            | WindowMessage::PointerCaptureChanged { id, .. }
+            | WindowMessage::PointerActivate { id, .. }
+            | WindowMessage::PointerWheel { id, .. }
+            | WindowMessage::PointerHWheel { id, .. } => Some(id as u32),
             _ => None
         }
     }
 
-    pub fn as_mouse_button(&self) -> Option<MouseButtonMessage> {
+    pub fn pointer_flags(&self) -> Option<PointerFlags> {
         match *self {
-            WindowMessage::LButtonDown { pos, modifiers } => Some(MouseButtonMessage {
-                action: MouseButtonAction::Down,
-                button: MouseButton::Left,
-                pos,
-                modifiers,
-            }),
-            WindowMessage::LButtonUp { pos, modifiers } => Some(MouseButtonMessage {
-                action: MouseButtonAction::Up,
-                button: MouseButton::Left,
-                pos,
-                modifiers,
-            }),
-            WindowMessage::LButtonDblClk { pos, modifiers } => Some(MouseButtonMessage {
-                action: MouseButtonAction::DoubleClick,
-                button: MouseButton::Left,
-                pos,
-                modifiers,
-            }),
-            WindowMessage::RButtonDown { pos, modifiers } => Some(MouseButtonMessage {
-                action: MouseButtonAction::Down,
-                button: MouseButton::Right,
-                pos,
-                modifiers,
-            }),
-            WindowMessage::RButtonUp { pos, modifiers } => Some(MouseButtonMessage {
-                action: MouseButtonAction::Up,
-                button: MouseButton::Right,
-                pos,
-                modifiers,
-            }),
-            WindowMessage::RButtonDblClk { pos, modifiers } => Some(MouseButtonMessage {
-                action: MouseButtonAction::DoubleClick,
-                button: MouseButton::Right,
-                pos,
-                modifiers,
-            }),
-            WindowMessage::MButtonDown { pos, modifiers } => Some(MouseButtonMessage {
-                action: MouseButtonAction::Down,
-                button: MouseButton::Middle,
-                pos,
-                modifiers,
-            }),
-            WindowMessage::MButtonUp { pos, modifiers } => Some(MouseButtonMessage {
-                action: MouseButtonAction::Up,
-                button: MouseButton::Middle,
-                pos,
-                modifiers,
-            }),
-            WindowMessage::MButtonDblClk { pos, modifiers } => Some(MouseButtonMessage {
-                action: MouseButtonAction::DoubleClick,
-                button: MouseButton::Middle,
-                pos,
-                modifiers,
-            }),
-            WindowMessage::XButtonDown { pos, modifiers, button } => Some(MouseButtonMessage {
-                action: MouseButtonAction::Down,
-                button: MouseButton::X(button),
-                pos,
-                modifiers: modifiers as _,
-            }),
-            WindowMessage::XButtonUp { pos, modifiers, button } => Some(MouseButtonMessage {
-                action: MouseButtonAction::Up,
-                button: MouseButton::X(button),
-                pos,
-                modifiers: modifiers as _,
-            }),
-            WindowMessage::XButtonDblClk { pos, modifiers, button } => Some(MouseButtonMessage {
-                action: MouseButtonAction::DoubleClick,
-                button: MouseButton::X(button),
-                pos,
-                modifiers: modifiers as _,
-            }),
+            WindowMessage::NcPointerUpdate { flags, .. }
+            | WindowMessage::NcPointerDown { flags, .. }
+            | WindowMessage::NcPointerUp { flags, .. }
+            | WindowMessage::PointerUpdate { flags, .. }
+            | WindowMessage::POINTERDOWN { flags, .. }
+            | WindowMessage::POINTERUP { flags, .. }
+            | WindowMessage::POINTERENTER { flags, .. }
+            | WindowMessage::POINTERLEAVE { flags, .. }
+            | WindowMessage::PointerRoutedTo { flags, .. }
+            | WindowMessage::PointerRoutedAway { flags, .. }
+            | WindowMessage::PointerRoutedReleased { flags, .. }
+            | WindowMessage::PointerCaptureChanged { flags, .. } => Some(PointerFlags(flags)),
+            _ => None
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn pointer_info(&self) -> Option<PointerInfo> {
+        let id = self.pointer_id()?;
+        let mut info: POINTER_INFO = unsafe { core::mem::zeroed() };
+        if unsafe { GetPointerInfo(id, &mut info) } == FALSE {
+            return None;
+        }
+        let pointer_type = match info.pointerType as DWORD {
+            PT_POINTER => Some(PointerType::Pointer),
+            PT_TOUCH => Some(PointerType::Touch),
+            PT_PEN => Some(PointerType::Pen),
+            PT_MOUSE => Some(PointerType::Mouse),
+            PT_TOUCHPAD => Some(PointerType::TouchPad),
+            _ => None
+        };
+        let button_change = match info.ButtonChangeType as DWORD {
+            POINTER_CHANGE_NONE => Some(PointerButtonChange::None),
+            POINTER_CHANGE_FIRSTBUTTON_DOWN => Some(PointerButtonChange::FirstButtonDown),
+            POINTER_CHANGE_FIRSTBUTTON_UP => Some(PointerButtonChange::FirstButtonUp),
+            POINTER_CHANGE_SECONDBUTTON_DOWN => Some(PointerButtonChange::SecondButtonDown),
+            POINTER_CHANGE_SECONDBUTTON_UP => Some(PointerButtonChange::SecondButtonUp),
+            POINTER_CHANGE_THIRDBUTTON_DOWN => Some(PointerButtonChange::ThirdButtonDown),
+            POINTER_CHANGE_THIRDBUTTON_UP => Some(PointerButtonChange::ThirdButtonUp),
+            POINTER_CHANGE_FOURTHBUTTON_DOWN => Some(PointerButtonChange::FourthButtonDown),
+            POINTER_CHANGE_FOURTHBUTTON_UP => Some(PointerButtonChange::FourthButtonUp),
+            POINTER_CHANGE_FIFTHBUTTON_DOWN => Some(PointerButtonChange::FifthButtonDown),
+            POINTER_CHANGE_FIFTHBUTTON_UP => Some(PointerButtonChange::FifthButtonUp),
+            _ => None
+        };
+        Some(PointerInfo {
+            pointer_type,
+            id: info.pointerId,
+            frame_id: info.frameId,
+            flags: info.pointerFlags,
+            source: info.sourceDevice,
+            target: info.hwndTarget,
+            pixel_location: (info.ptPixelLocation.x, info.ptPixelLocation.y),
+            himetric_location: (info.ptHimetricLocation.x, info.ptHimetricLocation.y),
+            time: info.dwTime,
+            button_change,
+        })
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn begin_paint(&self, window: HWND) -> Option<PaintContext> {
+        match *self {
+            WindowMessage::Paint => {
+                let mut paint: PAINTSTRUCT = unsafe { core::mem::zeroed() };
+                let hdc = unsafe { BeginPaint(window, &mut paint) };
+                if hdc.is_null() {
+                    None
+                } else {
+                    Some(PaintContext { window, paint })
+                }
+            },
             _ => None
         }
     }
-}
\ No newline at end of file
+
+    pub fn category(&self) -> MessageCategory {
+        match *self {
+            WindowMessage::MouseMove { .. }
+            | WindowMessage::LButtonDown { .. }
+            | WindowMessage::LButtonUp { .. }
+            | WindowMessage::LButtonDblClk { .. }
+            | WindowMessage::RButtonDown { .. }
+            | WindowMessage::RButtonUp { .. }
+            | WindowMessage::RButtonDblClk { .. }
+            | WindowMessage::MButtonDown { .. }
+            | WindowMessage::MButtonUp { .. }
+            | WindowMessage::MButtonDblClk { .. }
+            | WindowMessage::XButtonDown { .. }
+            | WindowMessage::XButtonUp { .. }
+            | WindowMessage::XButtonDblClk { .. }
+            | WindowMessage::MouseWheel { .. }
+            | WindowMessage::MouseHWheel { .. }
+            | WindowMessage::MouseHover { .. }
+            | WindowMessage::MouseLeave => MessageCategory::Mouse,
+            WindowMessage::KeyDown { .. }
+            | WindowMessage::KeyUp { .. }
+            | WindowMessage::SysKeyDown { .. }
+            | WindowMessage::SysKeyUp { .. }
+            | WindowMessage::Char { .. }
+            | WindowMessage::DeadChar { .. }
+            | WindowMessage::SysChar { .. }
+            | WindowMessage::SysDeadChar { .. }
+            | WindowMessage::UniChar { .. } => MessageCategory::Keyboard,
+            WindowMessage::NcCreate
+            | WindowMessage::NcDestroy
+            | WindowMessage::NcCalcSize { .. }
+            | WindowMessage::NcHitTest { .. }
+            | WindowMessage::NcPaint { .. }
+            | WindowMessage::NcActivate { .. }
+            | WindowMessage::NcMouseMove { .. }
+            | WindowMessage::NclButtonDown { .. }
+            | WindowMessage::NclButtonUp { .. }
+            | WindowMessage::NclButtonDblClk { .. }
+            | WindowMessage::NcRButtonDown { .. }
+            | WindowMessage::NcRButtonUp { .. }
+            | WindowMessage::NcRButtonDblClk { .. }
+            | WindowMessage::NcMButtonDown { .. }
+            | WindowMessage::NcMButtonUp { .. }
+            | WindowMessage::NcMButtonDblClk { .. }
+            | WindowMessage::NcXButtonDown { .. }
+            | WindowMessage::NcXButtonUp { .. }
+            | WindowMessage::NcXButtonDblClk { .. }
+            | WindowMessage::NcUahDrawCaption { .. }
+            | WindowMessage::NcUahDrawFrame { .. }
+            | WindowMessage::NcPointerUpdate { .. }
+            | WindowMessage::NcPointerDown { .. }
+            | WindowMessage::NcPointerUp { .. }
+            | WindowMessage::NcMouseHover
+            | WindowMessage::NcMouseLeave => MessageCategory::NonClient,
+            WindowMessage::Cut
+            | WindowMessage::Copy
+            | WindowMessage::Paste
+            | WindowMessage::Clear
+            | WindowMessage::RenderFormat
+            | WindowMessage::RenderAllFormats
+            | WindowMessage::DestroyClipboard
+            | WindowMessage::DrawClipboard
+            | WindowMessage::PaintClipboard { .. }
+            | WindowMessage::VScrollClipboard
+            | WindowMessage::SizeClipboard
+            | WindowMessage::AskCbFormatName { .. }
+            | WindowMessage::ChangeCbChain
+            | WindowMessage::HScrollClipboard
+            | WindowMessage::ClipboardUpdate => MessageCategory::Clipboard,
+            WindowMessage::ImeStartComposition
+            | WindowMessage::ImeEndComposition
+            | WindowMessage::ImeComposition
+            | WindowMessage::ImeSetContext { .. }
+            | WindowMessage::ImeNotify { .. }
+            | WindowMessage::ImeControl
+            | WindowMessage::ImeCompositionFull
+            | WindowMessage::ImeSelect
+            | WindowMessage::ImeChar
+            | WindowMessage::ImeRequest
+            | WindowMessage::ImeKeydown
+            | WindowMessage::ImeKeyup => MessageCategory::Ime,
+            WindowMessage::MdiCreate { .. }
+            | WindowMessage::MdiDestroy
+            | WindowMessage::MdiActivate { .. }
+            | WindowMessage::MdiRestore
+            | WindowMessage::MdiNext
+            | WindowMessage::MdiMaximize
+            | WindowMessage::MdiTile
+            | WindowMessage::MdiCascade
+            | WindowMessage::MdiIconArrange
+            | WindowMessage::MdiGetActive { .. }
+            | WindowMessage::MdiSetMenu
+            | WindowMessage::MdiRefreshMenu => MessageCategory::Mdi,
+            WindowMessage::DwmCompositionChanged
+            | WindowMessage::DwmNcRenderingChanged { .. }
+            | WindowMessage::DwmColorizationColorChanged { .. }
+            | WindowMessage::DwmWindowMaximizedChange { .. }
+            | WindowMessage::DwmSendIconIcThumbnail { .. }
+            | WindowMessage::DwmSendIconIcLivePreviewBitmap => MessageCategory::Dwm,
+            WindowMessage::PointerDeviceChange
+            | WindowMessage::PointerDeviceInRange
+            | WindowMessage::PointerDeviceOutOfRange
+            | WindowMessage::PointerUpdate { .. }
+            | WindowMessage::POINTERDOWN { .. }
+            | WindowMessage::POINTERUP { .. }
+            | WindowMessage::POINTERENTER { .. }
+            | WindowMessage::POINTERLEAVE { .. }
+            | WindowMessage::PointerActivate { .. }
+            | WindowMessage::PointerCaptureChanged { .. }
+            | WindowMessage::TouchHitTesting
+            | WindowMessage::PointerWheel { .. }
+            | WindowMessage::PointerHWheel { .. }
+            | WindowMessage::PointerRoutedTo { .. }
+            | WindowMessage::PointerRoutedAway { .. }
+            | WindowMessage::PointerRoutedReleased { .. } => MessageCategory::Pointer,
+            WindowMessage::InitMenu
+            | WindowMessage::InitMenuPopup
+            | WindowMessage::MenuSelect { .. }
+            | WindowMessage::MenuChar
+            | WindowMessage::MenuRButtonUp
+            | WindowMessage::MenuDrag { .. }
+            | WindowMessage::MenuGetObject { .. }
+            | WindowMessage::UninitMenuPopup
+            | WindowMessage::MenuCommand { .. }
+            | WindowMessage::EnterMenuLoop { .. }
+            | WindowMessage::ExitMenuLoop { .. }
+            | WindowMessage::NextMenu { .. }
+            | WindowMessage::ContextMenu { .. } => MessageCategory::Menu,
+            WindowMessage::CtlColorMsgBox { .. }
+            | WindowMessage::CtlColorEdit { .. }
+            | WindowMessage::CtlColorListBox { .. }
+            | WindowMessage::CtlColorBtn { .. }
+            | WindowMessage::CtlColorDlg { .. }
+            | WindowMessage::CtlColorScrollbar { .. }
+            | WindowMessage::CtlColorStatic { .. } => MessageCategory::ControlColor,
+            _ => MessageCategory::Other
+        }
+    }
+
+    pub fn is_mouse(&self) -> bool {
+        self.category() == MessageCategory::Mouse
+    }
+
+    pub fn is_keyboard(&self) -> bool {
+        self.category() == MessageCategory::Keyboard
+    }
+
+    pub fn is_nonclient(&self) -> bool {
+        self.category() == MessageCategory::NonClient
+    }
+
+    /// The undocumented theme drawing messages. A window with a custom frame replies 0
+    /// instead of forwarding them to `DefWindowProc`, which would paint the themed caption
+    /// and borders over its own.
+    pub fn is_uah_draw(&self) -> bool {
+        matches!(self, WindowMessage::NcUahDrawCaption { .. } | WindowMessage::NcUahDrawFrame { .. })
+    }
+
+    pub fn is_clipboard(&self) -> bool {
+        self.category() == MessageCategory::Clipboard
+    }
+
+    pub fn is_ime(&self) -> bool {
+        self.category() == MessageCategory::Ime
+    }
+
+    pub fn is_mdi(&self) -> bool {
+        self.category() == MessageCategory::Mdi
+    }
+
+    pub fn is_dwm(&self) -> bool {
+        self.category() == MessageCategory::Dwm
+    }
+
+    pub fn is_pointer(&self) -> bool {
+        self.category() == MessageCategory::Pointer
+    }
+
+    /// Whether the message points at memory that is only valid until the window procedure
+    /// returns, so it must not be queued for later. Handles do not count.
+    pub fn requires_dispatch_context(&self) -> bool {
+        matches!(
+            self,
+            WindowMessage::Create { .. }
+                | WindowMessage::GetMinMaxInfo { .. }
+                | WindowMessage::WindowPosChanging { .. }
+                | WindowMessage::WindowPosChanged { .. }
+                | WindowMessage::CopyData { .. }
+                | WindowMessage::Help { .. }
+                | WindowMessage::StyleChanging { .. }
+                | WindowMessage::StyleChanged { .. }
+                | WindowMessage::GetDlgCode { .. }
+                | WindowMessage::NcCalcSize { .. }
+                | WindowMessage::GestureNotify { .. }
+                | WindowMessage::MenuGetObject { .. }
+                | WindowMessage::NextMenu { .. }
+                | WindowMessage::Sizing { .. }
+                | WindowMessage::Moving { .. }
+                | WindowMessage::PowerBroadcast { .. }
+                | WindowMessage::MdiCreate { .. }
+                | WindowMessage::MdiGetActive { .. }
+                | WindowMessage::DpiChanged { .. }
+                | WindowMessage::GetDpiScaledSize { .. }
+                | WindowMessage::AskCbFormatName { .. }
+                | WindowMessage::GetTitleBarInfoEx { .. }
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+/// Changes on every write to the clipboard. A monitor that records it right after its own
+/// writes can skip the `ClipboardUpdate` they cause.
+pub fn clipboard_sequence() -> u32 {
+    unsafe { GetClipboardSequenceNumber() }
+}
+
+#[cfg(feature = "std")]
+pub fn clipboard_has_format(format: UINT) -> bool {
+    unsafe { IsClipboardFormatAvailable(format) != FALSE }
+}
+
+#[cfg(feature = "std")]
+/// Covers every text format, since the system converts between them on demand.
+pub fn clipboard_has_text() -> bool {
+    const FORMAT: UINT = CF_UNICODETEXT as _;
+    clipboard_has_format(FORMAT)
+}
+
+#[cfg(feature = "std")]
+pub fn clipboard_has_files() -> bool {
+    const FORMAT: UINT = CF_HDROP as _;
+    clipboard_has_format(FORMAT)
+}
+
+/// The id of the client-area message for `action` on `button`. Either extra button gives
+/// the `WM_XBUTTON*` id; which one is in the message's `w_param`.
+pub const fn mouse_message_id(button: MouseButton, action: MouseButtonAction) -> UINT {
+    match (button, action) {
+        (MouseButton::Left, MouseButtonAction::Down) => WM_LBUTTONDOWN,
+        (MouseButton::Left, MouseButtonAction::Up) => WM_LBUTTONUP,
+        (MouseButton::Left, MouseButtonAction::DoubleClick) => WM_LBUTTONDBLCLK,
+        (MouseButton::Right, MouseButtonAction::Down) => WM_RBUTTONDOWN,
+        (MouseButton::Right, MouseButtonAction::Up) => WM_RBUTTONUP,
+        (MouseButton::Right, MouseButtonAction::DoubleClick) => WM_RBUTTONDBLCLK,
+        (MouseButton::Middle, MouseButtonAction::Down) => WM_MBUTTONDOWN,
+        (MouseButton::Middle, MouseButtonAction::Up) => WM_MBUTTONUP,
+        (MouseButton::Middle, MouseButtonAction::DoubleClick) => WM_MBUTTONDBLCLK,
+        (MouseButton::X(_), MouseButtonAction::Down) => WM_XBUTTONDOWN,
+        (MouseButton::X(_), MouseButtonAction::Up) => WM_XBUTTONUP,
+        (MouseButton::X(_), MouseButtonAction::DoubleClick) => WM_XBUTTONDBLCLK,
+    }
+}
+
+/// The inverse of `mouse_message_id`. The `WM_XBUTTON*` ids give `MouseButton::X(0)`,
+/// since the id alone does not say which extra button it was.
+pub const fn mouse_button_from_id(msg: UINT) -> Option<(MouseButton, MouseButtonAction)> {
+    Some(match msg {
+        WM_LBUTTONDOWN => (MouseButton::Left, MouseButtonAction::Down),
+        WM_LBUTTONUP => (MouseButton::Left, MouseButtonAction::Up),
+        WM_LBUTTONDBLCLK => (MouseButton::Left, MouseButtonAction::DoubleClick),
+        WM_RBUTTONDOWN => (MouseButton::Right, MouseButtonAction::Down),
+        WM_RBUTTONUP => (MouseButton::Right, MouseButtonAction::Up),
+        WM_RBUTTONDBLCLK => (MouseButton::Right, MouseButtonAction::DoubleClick),
+        WM_MBUTTONDOWN => (MouseButton::Middle, MouseButtonAction::Down),
+        WM_MBUTTONUP => (MouseButton::Middle, MouseButtonAction::Up),
+        WM_MBUTTONDBLCLK => (MouseButton::Middle, MouseButtonAction::DoubleClick),
+        WM_XBUTTONDOWN => (MouseButton::X(0), MouseButtonAction::Down),
+        WM_XBUTTONUP => (MouseButton::X(0), MouseButtonAction::Up),
+        WM_XBUTTONDBLCLK => (MouseButton::X(0), MouseButtonAction::DoubleClick),
+        _ => return None
+    })
+}
+
+pub fn message_name(id: u32) -> Option<&'static str> {
+    match id {
+        WM_NULL => Some("WM_NULL"),
+        WM_CREATE => Some("WM_CREATE"),
+        WM_DESTROY => Some("WM_DESTROY"),
+        WM_MOVE => Some("WM_MOVE"),
+        WM_SIZE => Some("WM_SIZE"),
+        WM_ACTIVATE => Some("WM_ACTIVATE"),
+        WM_SETFOCUS => Some("WM_SETFOCUS"),
+        WM_KILLFOCUS => Some("WM_KILLFOCUS"),
+        WM_ENABLE => Some("WM_ENABLE"),
+        WM_SETREDRAW => Some("WM_SETREDRAW"),
+        WM_SETTEXT => Some("WM_SETTEXT"),
+        WM_GETTEXT => Some("WM_GETTEXT"),
+        WM_GETTEXTLENGTH => Some("WM_GETTEXTLENGTH"),
+        WM_PAINT => Some("WM_PAINT"),
+        WM_CLOSE => Some("WM_CLOSE"),
+        WM_QUERYENDSESSION => Some("WM_QUERYENDSESSION"),
+        WM_QUERYOPEN => Some("WM_QUERYOPEN"),
+        WM_ENDSESSION => Some("WM_ENDSESSION"),
+        WM_QUIT => Some("WM_QUIT"),
+        WM_ERASEBKGND => Some("WM_ERASEBKGND"),
+        WM_SYSCOLORCHANGE => Some("WM_SYSCOLORCHANGE"),
+        WM_SHOWWINDOW => Some("WM_SHOWWINDOW"),
+        WM_SETTINGCHANGE => Some("WM_SETTINGCHANGE"),
+        WM_DEVMODECHANGE => Some("WM_DEVMODECHANGE"),
+        WM_ACTIVATEAPP => Some("WM_ACTIVATEAPP"),
+        WM_FONTCHANGE => Some("WM_FONTCHANGE"),
+        WM_TIMECHANGE => Some("WM_TIMECHANGE"),
+        WM_CANCELMODE => Some("WM_CANCELMODE"),
+        WM_SETCURSOR => Some("WM_SETCURSOR"),
+        WM_MOUSEACTIVATE => Some("WM_MOUSEACTIVATE"),
+        WM_CHILDACTIVATE => Some("WM_CHILDACTIVATE"),
+        WM_QUEUESYNC => Some("WM_QUEUESYNC"),
+        WM_GETMINMAXINFO => Some("WM_GETMINMAXINFO"),
+        WM_PAINTICON => Some("WM_PAINTICON"),
+        WM_ICONERASEBKGND => Some("WM_ICONERASEBKGND"),
+        WM_NEXTDLGCTL => Some("WM_NEXTDLGCTL"),
+        WM_SPOOLERSTATUS => Some("WM_SPOOLERSTATUS"),
+        WM_DRAWITEM => Some("WM_DRAWITEM"),
+        WM_MEASUREITEM => Some("WM_MEASUREITEM"),
+        WM_DELETEITEM => Some("WM_DELETEITEM"),
+        WM_VKEYTOITEM => Some("WM_VKEYTOITEM"),
+        WM_CHARTOITEM => Some("WM_CHARTOITEM"),
+        WM_SETFONT => Some("WM_SETFONT"),
+        WM_GETFONT => Some("WM_GETFONT"),
+        WM_SETHOTKEY => Some("WM_SETHOTKEY"),
+        WM_GETHOTKEY => Some("WM_GETHOTKEY"),
+        WM_QUERYDRAGICON => Some("WM_QUERYDRAGICON"),
+        WM_COMPAREITEM => Some("WM_COMPAREITEM"),
+        WM_GETOBJECT => Some("WM_GETOBJECT"),
+        WM_COMPACTING => Some("WM_COMPACTING"),
+        WM_COMMNOTIFY => Some("WM_COMMNOTIFY"),
+        WM_WINDOWPOSCHANGING => Some("WM_WINDOWPOSCHANGING"),
+        WM_WINDOWPOSCHANGED => Some("WM_WINDOWPOSCHANGED"),
+        WM_POWER => Some("WM_POWER"),
+        WM_COPYDATA => Some("WM_COPYDATA"),
+        WM_CANCELJOURNAL => Some("WM_CANCELJOURNAL"),
+        WM_NOTIFY => Some("WM_NOTIFY"),
+        WM_INPUTLANGCHANGEREQUEST => Some("WM_INPUTLANGCHANGEREQUEST"),
+        WM_INPUTLANGCHANGE => Some("WM_INPUTLANGCHANGE"),
+        WM_TCARD => Some("WM_TCARD"),
+        WM_HELP => Some("WM_HELP"),
+        WM_USERCHANGED => Some("WM_USERCHANGED"),
+        WM_NOTIFYFORMAT => Some("WM_NOTIFYFORMAT"),
+        WM_CONTEXTMENU => Some("WM_CONTEXTMENU"),
+        WM_STYLECHANGING => Some("WM_STYLECHANGING"),
+        WM_STYLECHANGED => Some("WM_STYLECHANGED"),
+        WM_DISPLAYCHANGE => Some("WM_DISPLAYCHANGE"),
+        WM_GETICON => Some("WM_GETICON"),
+        WM_SETICON => Some("WM_SETICON"),
+        WM_NCCREATE => Some("WM_NCCREATE"),
+        WM_NCDESTROY => Some("WM_NCDESTROY"),
+        WM_NCCALCSIZE => Some("WM_NCCALCSIZE"),
+        WM_NCHITTEST => Some("WM_NCHITTEST"),
+        WM_NCPAINT => Some("WM_NCPAINT"),
+        WM_NCACTIVATE => Some("WM_NCACTIVATE"),
+        WM_GETDLGCODE => Some("WM_GETDLGCODE"),
+        WM_SYNCPAINT => Some("WM_SYNCPAINT"),
+        WM_NCMOUSEMOVE => Some("WM_NCMOUSEMOVE"),
+        WM_NCLBUTTONDOWN => Some("WM_NCLBUTTONDOWN"),
+        WM_NCLBUTTONUP => Some("WM_NCLBUTTONUP"),
+        WM_NCLBUTTONDBLCLK => Some("WM_NCLBUTTONDBLCLK"),
+        WM_NCRBUTTONDOWN => Some("WM_NCRBUTTONDOWN"),
+        WM_NCRBUTTONUP => Some("WM_NCRBUTTONUP"),
+        WM_NCRBUTTONDBLCLK => Some("WM_NCRBUTTONDBLCLK"),
+        WM_NCMBUTTONDOWN => Some("WM_NCMBUTTONDOWN"),
+        WM_NCMBUTTONUP => Some("WM_NCMBUTTONUP"),
+        WM_NCMBUTTONDBLCLK => Some("WM_NCMBUTTONDBLCLK"),
+        WM_NCXBUTTONDOWN => Some("WM_NCXBUTTONDOWN"),
+        WM_NCXBUTTONUP => Some("WM_NCXBUTTONUP"),
+        WM_NCXBUTTONDBLCLK => Some("WM_NCXBUTTONDBLCLK"),
+        WM_INPUT_DEVICE_CHANGE => Some("WM_INPUT_DEVICE_CHANGE"),
+        WM_INPUT => Some("WM_INPUT"),
+        WM_KEYDOWN => Some("WM_KEYDOWN"),
+        WM_KEYUP => Some("WM_KEYUP"),
+        WM_CHAR => Some("WM_CHAR"),
+        WM_DEADCHAR => Some("WM_DEADCHAR"),
+        WM_SYSKEYDOWN => Some("WM_SYSKEYDOWN"),
+        WM_SYSKEYUP => Some("WM_SYSKEYUP"),
+        WM_SYSCHAR => Some("WM_SYSCHAR"),
+        WM_SYSDEADCHAR => Some("WM_SYSDEADCHAR"),
+        WM_UNICHAR => Some("WM_UNICHAR"),
+        WM_IME_STARTCOMPOSITION => Some("WM_IME_STARTCOMPOSITION"),
+        WM_IME_ENDCOMPOSITION => Some("WM_IME_ENDCOMPOSITION"),
+        WM_IME_COMPOSITION => Some("WM_IME_COMPOSITION"),
+        WM_INITDIALOG => Some("WM_INITDIALOG"),
+        WM_COMMAND => Some("WM_COMMAND"),
+        WM_SYSCOMMAND => Some("WM_SYSCOMMAND"),
+        WM_TIMER => Some("WM_TIMER"),
+        WM_HSCROLL => Some("WM_HSCROLL"),
+        WM_VSCROLL => Some("WM_VSCROLL"),
+        WM_INITMENU => Some("WM_INITMENU"),
+        WM_INITMENUPOPUP => Some("WM_INITMENUPOPUP"),
+        WM_GESTURE => Some("WM_GESTURE"),
+        WM_GESTURENOTIFY => Some("WM_GESTURENOTIFY"),
+        WM_MENUSELECT => Some("WM_MENUSELECT"),
+        WM_MENUCHAR => Some("WM_MENUCHAR"),
+        WM_ENTERIDLE => Some("WM_ENTERIDLE"),
+        WM_MENURBUTTONUP => Some("WM_MENURBUTTONUP"),
+        WM_MENUDRAG => Some("WM_MENUDRAG"),
+        WM_MENUGETOBJECT => Some("WM_MENUGETOBJECT"),
+        WM_UNINITMENUPOPUP => Some("WM_UNINITMENUPOPUP"),
+        WM_MENUCOMMAND => Some("WM_MENUCOMMAND"),
+        WM_CHANGEUISTATE => Some("WM_CHANGEUISTATE"),
+        WM_UPDATEUISTATE => Some("WM_UPDATEUISTATE"),
+        WM_QUERYUISTATE => Some("WM_QUERYUISTATE"),
+        WM_CTLCOLORMSGBOX => Some("WM_CTLCOLORMSGBOX"),
+        WM_CTLCOLOREDIT => Some("WM_CTLCOLOREDIT"),
+        WM_CTLCOLORLISTBOX => Some("WM_CTLCOLORLISTBOX"),
+        WM_CTLCOLORBTN => Some("WM_CTLCOLORBTN"),
+        WM_CTLCOLORDLG => Some("WM_CTLCOLORDLG"),
+        WM_CTLCOLORSCROLLBAR => Some("WM_CTLCOLORSCROLLBAR"),
+        WM_CTLCOLORSTATIC => Some("WM_CTLCOLORSTATIC"),
+        WM_MOUSEMOVE => Some("WM_MOUSEMOVE"),
+        WM_LBUTTONDOWN => Some("WM_LBUTTONDOWN"),
+        WM_LBUTTONUP => Some("WM_LBUTTONUP"),
+        WM_LBUTTONDBLCLK => Some("WM_LBUTTONDBLCLK"),
+        WM_RBUTTONDOWN => Some("WM_RBUTTONDOWN"),
+        WM_RBUTTONUP => Some("WM_RBUTTONUP"),
+        WM_RBUTTONDBLCLK => Some("WM_RBUTTONDBLCLK"),
+        WM_MBUTTONDOWN => Some("WM_MBUTTONDOWN"),
+        WM_MBUTTONUP => Some("WM_MBUTTONUP"),
+        WM_MBUTTONDBLCLK => Some("WM_MBUTTONDBLCLK"),
+        WM_MOUSEWHEEL => Some("WM_MOUSEWHEEL"),
+        WM_XBUTTONDOWN => Some("WM_XBUTTONDOWN"),
+        WM_XBUTTONUP => Some("WM_XBUTTONUP"),
+        WM_XBUTTONDBLCLK => Some("WM_XBUTTONDBLCLK"),
+        WM_MOUSEHWHEEL => Some("WM_MOUSEHWHEEL"),
+        WM_PARENTNOTIFY => Some("WM_PARENTNOTIFY"),
+        WM_ENTERMENULOOP => Some("WM_ENTERMENULOOP"),
+        WM_EXITMENULOOP => Some("WM_EXITMENULOOP"),
+        WM_NEXTMENU => Some("WM_NEXTMENU"),
+        WM_SIZING => Some("WM_SIZING"),
+        WM_CAPTURECHANGED => Some("WM_CAPTURECHANGED"),
+        WM_MOVING => Some("WM_MOVING"),
+        WM_POWERBROADCAST => Some("WM_POWERBROADCAST"),
+        WM_DEVICECHANGE => Some("WM_DEVICECHANGE"),
+        WM_MDICREATE => Some("WM_MDICREATE"),
+        WM_MDIDESTROY => Some("WM_MDIDESTROY"),
+        WM_MDIACTIVATE => Some("WM_MDIACTIVATE"),
+        WM_MDIRESTORE => Some("WM_MDIRESTORE"),
+        WM_MDINEXT => Some("WM_MDINEXT"),
+        WM_MDIMAXIMIZE => Some("WM_MDIMAXIMIZE"),
+        WM_MDITILE => Some("WM_MDITILE"),
+        WM_MDICASCADE => Some("WM_MDICASCADE"),
+        WM_MDIICONARRANGE => Some("WM_MDIICONARRANGE"),
+        WM_MDIGETACTIVE => Some("WM_MDIGETACTIVE"),
+        WM_MDISETMENU => Some("WM_MDISETMENU"),
+        WM_ENTERSIZEMOVE => Some("WM_ENTERSIZEMOVE"),
+        WM_EXITSIZEMOVE => Some("WM_EXITSIZEMOVE"),
+        WM_DROPFILES => Some("WM_DROPFILES"),
+        WM_MDIREFRESHMENU => Some("WM_MDIREFRESHMENU"),
+        WM_POINTERDEVICECHANGE => Some("WM_POINTERDEVICECHANGE"),
+        WM_POINTERDEVICEINRANGE => Some("WM_POINTERDEVICEINRANGE"),
+        WM_POINTERDEVICEOUTOFRANGE => Some("WM_POINTERDEVICEOUTOFRANGE"),
+        WM_TOUCH => Some("WM_TOUCH"),
+        WM_NCPOINTERUPDATE => Some("WM_NCPOINTERUPDATE"),
+        WM_NCPOINTERDOWN => Some("WM_NCPOINTERDOWN"),
+        WM_NCPOINTERUP => Some("WM_NCPOINTERUP"),
+        WM_POINTERUPDATE => Some("WM_POINTERUPDATE"),
+        WM_POINTERDOWN => Some("WM_POINTERDOWN"),
+        WM_POINTERUP => Some("WM_POINTERUP"),
+        WM_POINTERENTER => Some("WM_POINTERENTER"),
+        WM_POINTERLEAVE => Some("WM_POINTERLEAVE"),
+        WM_POINTERACTIVATE => Some("WM_POINTERACTIVATE"),
+        WM_POINTERCAPTURECHANGED => Some("WM_POINTERCAPTURECHANGED"),
+        WM_TOUCHHITTESTING => Some("WM_TOUCHHITTESTING"),
+        WM_POINTERWHEEL => Some("WM_POINTERWHEEL"),
+        WM_POINTERHWHEEL => Some("WM_POINTERHWHEEL"),
+        WM_POINTERROUTEDTO => Some("WM_POINTERROUTEDTO"),
+        WM_POINTERROUTEDAWAY => Some("WM_POINTERROUTEDAWAY"),
+        WM_POINTERROUTEDRELEASED => Some("WM_POINTERROUTEDRELEASED"),
+        WM_IME_SETCONTEXT => Some("WM_IME_SETCONTEXT"),
+        WM_IME_NOTIFY => Some("WM_IME_NOTIFY"),
+        WM_IME_CONTROL => Some("WM_IME_CONTROL"),
+        WM_IME_COMPOSITIONFULL => Some("WM_IME_COMPOSITIONFULL"),
+        WM_IME_SELECT => Some("WM_IME_SELECT"),
+        WM_IME_CHAR => Some("WM_IME_CHAR"),
+        WM_IME_REQUEST => Some("WM_IME_REQUEST"),
+        WM_IME_KEYDOWN => Some("WM_IME_KEYDOWN"),
+        WM_IME_KEYUP => Some("WM_IME_KEYUP"),
+        WM_MOUSEHOVER => Some("WM_MOUSEHOVER"),
+        WM_MOUSELEAVE => Some("WM_MOUSELEAVE"),
+        WM_NCMOUSEHOVER => Some("WM_NCMOUSEHOVER"),
+        WM_NCMOUSELEAVE => Some("WM_NCMOUSELEAVE"),
+        WM_WTSSESSION_CHANGE => Some("WM_WTSSESSION_CHANGE"),
+        WM_TABLET_FIRST => Some("WM_TABLET_FIRST"),
+        WM_TABLET_LAST => Some("WM_TABLET_LAST"),
+        WM_DPICHANGED => Some("WM_DPICHANGED"),
+        WM_DPICHANGED_BEFOREPARENT => Some("WM_DPICHANGED_BEFOREPARENT"),
+        WM_DPICHANGED_AFTERPARENT => Some("WM_DPICHANGED_AFTERPARENT"),
+        WM_GETDPISCALEDSIZE => Some("WM_GETDPISCALEDSIZE"),
+        WM_CUT => Some("WM_CUT"),
+        WM_COPY => Some("WM_COPY"),
+        WM_PASTE => Some("WM_PASTE"),
+        WM_CLEAR => Some("WM_CLEAR"),
+        WM_UNDO => Some("WM_UNDO"),
+        WM_RENDERFORMAT => Some("WM_RENDERFORMAT"),
+        WM_RENDERALLFORMATS => Some("WM_RENDERALLFORMATS"),
+        WM_DESTROYCLIPBOARD => Some("WM_DESTROYCLIPBOARD"),
+        WM_DRAWCLIPBOARD => Some("WM_DRAWCLIPBOARD"),
+        WM_PAINTCLIPBOARD => Some("WM_PAINTCLIPBOARD"),
+        WM_VSCROLLCLIPBOARD => Some("WM_VSCROLLCLIPBOARD"),
+        WM_SIZECLIPBOARD => Some("WM_SIZECLIPBOARD"),
+        WM_ASKCBFORMATNAME => Some("WM_ASKCBFORMATNAME"),
+        WM_CHANGECBCHAIN => Some("WM_CHANGECBCHAIN"),
+        WM_HSCROLLCLIPBOARD => Some("WM_HSCROLLCLIPBOARD"),
+        WM_QUERYNEWPALETTE => Some("WM_QUERYNEWPALETTE"),
+        WM_PALETTEISCHANGING => Some("WM_PALETTEISCHANGING"),
+        WM_PALETTECHANGED => Some("WM_PALETTECHANGED"),
+        WM_HOTKEY => Some("WM_HOTKEY"),
+        WM_PRINT => Some("WM_PRINT"),
+        WM_PRINTCLIENT => Some("WM_PRINTCLIENT"),
+        WM_APPCOMMAND => Some("WM_APPCOMMAND"),
+        WM_THEMECHANGED => Some("WM_THEMECHANGED"),
+        WM_CLIPBOARDUPDATE => Some("WM_CLIPBOARDUPDATE"),
+        WM_DWMCOMPOSITIONCHANGED => Some("WM_DWMCOMPOSITIONCHANGED"),
+        WM_DWMNCRENDERINGCHANGED => Some("WM_DWMNCRENDERINGCHANGED"),
+        WM_DWMCOLORIZATIONCOLORCHANGED => Some("WM_DWMCOLORIZATIONCOLORCHANGED"),
+        WM_DWMWINDOWMAXIMIZEDCHANGE => Some("WM_DWMWINDOWMAXIMIZEDCHANGE"),
+        WM_DWMSENDICONICTHUMBNAIL => Some("WM_DWMSENDICONICTHUMBNAIL"),
+        WM_DWMSENDICONICLIVEPREVIEWBITMAP => Some("WM_DWMSENDICONICLIVEPREVIEWBITMAP"),
+        WM_GETTITLEBARINFOEX => Some("WM_GETTITLEBARINFOEX"),
+        WM_HANDHELDFIRST => Some("WM_HANDHELDFIRST"),
+        WM_HANDHELDLAST => Some("WM_HANDHELDLAST"),
+        WM_AFXFIRST => Some("WM_AFXFIRST"),
+        WM_AFXLAST => Some("WM_AFXLAST"),
+        WM_PENWINFIRST => Some("WM_PENWINFIRST"),
+        WM_PENWINLAST => Some("WM_PENWINLAST"),
+        0x00AE => Some("WM_NCUAHDRAWCAPTION"),
+        0x00AF => Some("WM_NCUAHDRAWFRAME"),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    fn message(msg: UINT, w_param: WPARAM, l_param: LPARAM) -> WindowMessage {
+        match WindowEvent::parse_checked(msg, w_param, l_param) {
+            WindowEvent::Message(message) => message,
+            other => panic!("{:?} is not a message", other)
+        }
+    }
+
+    #[test]
+    fn timer_id_keeps_pointer_width() {
+        let id = usize::MAX - 1;
+        let timer = message(WM_TIMER, id, 0).as_timer().unwrap();
+        assert_eq!(timer.id, id);
+        assert!(timer.callback.is_none());
+    }
+
+    #[test]
+    fn categories() {
+        for (msg, category) in [
+            (WM_MOUSEMOVE, MessageCategory::Mouse),
+            (WM_MOUSEWHEEL, MessageCategory::Mouse),
+            (WM_KEYDOWN, MessageCategory::Keyboard),
+            (WM_SYSCHAR, MessageCategory::Keyboard),
+            (WM_NCHITTEST, MessageCategory::NonClient),
+            (WM_NCLBUTTONDOWN, MessageCategory::NonClient),
+            (WM_CLIPBOARDUPDATE, MessageCategory::Clipboard),
+            (WM_IME_STARTCOMPOSITION, MessageCategory::Ime),
+            (WM_MDIACTIVATE, MessageCategory::Mdi),
+            (WM_DWMCOMPOSITIONCHANGED, MessageCategory::Dwm),
+            (WM_POINTERUPDATE, MessageCategory::Pointer),
+            (WM_INITMENU, MessageCategory::Menu),
+            (WM_CTLCOLORBTN, MessageCategory::ControlColor),
+            (WM_DESTROY, MessageCategory::Other),
+        ] {
+            assert_eq!(message(msg, 0, 0).category(), category, "{}", message_name(msg).unwrap());
+        }
+        assert!(message(WM_LBUTTONUP, 0, 0).is_mouse());
+        assert!(!message(WM_LBUTTONUP, 0, 0).is_keyboard());
+    }
+
+    #[test]
+    fn icon_sizes() {
+        assert!(matches!(message(WM_GETICON, ICON_SMALL as WPARAM, 96), WindowMessage::GetIcon { size: IconSize::Small, dpi: 96 }));
+        assert!(matches!(message(WM_GETICON, ICON_BIG as WPARAM, 0), WindowMessage::GetIcon { size: IconSize::Big, .. }));
+        let icon = 0x1230 as HICON;
+        assert!(matches!(message(WM_SETICON, ICON_BIG as WPARAM, icon as LPARAM), WindowMessage::SetIcon { size: IconSize::Big, icon: i } if i == icon));
+        assert!(matches!(IconSize::from_code(ICON_SMALL2 as usize), Some(IconSize::Small2)));
+        assert!(IconSize::from_code(3).is_none());
+        assert!(WindowEvent::try_parse(WM_GETICON, 3, 0).is_none());
+    }
+
+    #[test]
+    fn key_message_display() {
+        let key = KeyMessage { up: false, sys: false, code: VK_RETURN as WPARAM, info: KeyInfo(0) };
+        assert_eq!(std::format!("{}", key), "KeyDown Return");
+        let key = KeyMessage { up: true, sys: true, code: 0xFF, info: KeyInfo(0) };
+        assert_eq!(std::format!("{}", key), "SysKeyUp 0xFF");
+    }
+
+    #[test]
+    fn enter_idle_reasons() {
+        let dialog = 0x40 as HWND;
+        let idle = message(WM_ENTERIDLE, MSGF_DIALOGBOX as WPARAM, dialog as LPARAM).as_enter_idle().unwrap();
+        assert_eq!(idle.reason, IdleReason::Dialog);
+        assert_eq!(idle.window, dialog);
+        let idle = message(WM_ENTERIDLE, MSGF_MENU as WPARAM, 0).as_enter_idle().unwrap();
+        assert_eq!(idle.reason, IdleReason::Menu);
+        assert!(message(WM_ENTERIDLE, 5, 0).as_enter_idle().is_none());
+    }
+
+    #[test]
+    fn activate_app_flag() {
+        let app = message(WM_ACTIVATEAPP, TRUE as WPARAM, 1234).as_activate_app().unwrap();
+        assert!(app.activated);
+        assert_eq!(app.thread_id, 1234);
+        assert!(!message(WM_ACTIVATEAPP, FALSE as WPARAM, 0).as_activate_app().unwrap().activated);
+    }
+
+    #[test]
+    fn set_new_style_writes_through() {
+        let mut style = STYLESTRUCT { styleOld: WS_CHILD, styleNew: WS_CHILD | WS_VISIBLE };
+        let mut changing = message(WM_STYLECHANGING, GWL_STYLE as WPARAM, &mut style as *mut _ as LPARAM);
+        assert_eq!(changing.old_style(), Some(WS_CHILD));
+        assert!(changing.set_new_style(WS_POPUP));
+        assert_eq!(style.styleNew, WS_POPUP);
+        let mut changed = message(WM_STYLECHANGED, GWL_STYLE as WPARAM, &mut style as *mut _ as LPARAM);
+        assert!(!changed.set_new_style(0));
+        assert_eq!(style.styleNew, WS_POPUP);
+    }
+
+    #[test]
+    fn next_menu_writes_through() {
+        let mut next: MDINEXTMENU = unsafe { core::mem::zeroed() };
+        let mut menu = message(WM_NEXTMENU, VK_RIGHT as WPARAM, &mut next as *mut _ as LPARAM);
+        let (key, data) = menu.next_menu().unwrap();
+        assert_eq!(key, Some(VirtualKey::Right));
+        data.hwndNext = 0x80 as HWND;
+        assert_eq!(next.hwndNext, 0x80 as HWND);
+    }
+
+    #[test]
+    fn nc_activate_suppress() {
+        let activate = message(WM_NCACTIVATE, FALSE as WPARAM, -1).as_nc_activate().unwrap();
+        assert!(!activate.active);
+        assert!(matches!(activate.update_region, NcActivateRegion::Suppress));
+        let activate = message(WM_NCACTIVATE, TRUE as WPARAM, 0).as_nc_activate().unwrap();
+        assert!(activate.active);
+        assert!(matches!(activate.update_region, NcActivateRegion::Entire));
+        assert!(matches!(message(WM_NCACTIVATE, TRUE as WPARAM, 0x44).as_nc_activate().unwrap().update_region, NcActivateRegion::Region(region) if region as LPARAM == 0x44));
+    }
+
+    #[test]
+    fn set_cursor_trigger() {
+        let window = 0x90 as HWND;
+        let l_param = (HTCLIENT as u16 as u32 | WM_LBUTTONDOWN << 16) as LPARAM;
+        let cursor = message(WM_SETCURSOR, window as WPARAM, l_param).as_set_cursor().unwrap();
+        assert_eq!(cursor.window, window);
+        assert_eq!(cursor.hit_test, Some(HitTest::Client));
+        assert_eq!(cursor.trigger_message, WM_LBUTTONDOWN);
+        assert_eq!(cursor.trigger_name(), Some("WM_LBUTTONDOWN"));
+    }
+
+    #[test]
+    fn wheel_axes() {
+        let w_param = (MK_SHIFT as u32 | (-120i16 as u16 as u32) << 16) as WPARAM;
+        let wheel = message(WM_MOUSEWHEEL, w_param, 0).as_mouse_wheel().unwrap();
+        assert_eq!(wheel.axis, WheelAxis::Vertical);
+        assert_eq!(wheel.delta, -120);
+        assert!(wheel.modifiers.shift());
+        let wheel = message(WM_MOUSEHWHEEL, 120 << 16, 0).as_mouse_wheel().unwrap();
+        assert_eq!(wheel.axis, WheelAxis::Horizontal);
+        assert_eq!(wheel.delta, 120);
+    }
+
+    #[test]
+    fn parse_const_in_const() {
+        const DOWN: Option<WindowMessage> = WindowEvent::parse_const(WM_LBUTTONDOWN, MK_LBUTTON, 0x0014_000A);
+        const DESTROY: Option<WindowMessage> = WindowEvent::parse_const(WM_DESTROY, 0, 0);
+        const NONE: Option<WindowMessage> = WindowEvent::parse_const(WM_CREATE, 0, 0);
+        assert!(matches!(DOWN, Some(WindowMessage::LButtonDown { modifiers: MK_LBUTTON, pos: MousePos { x: 10, y: 20 } })));
+        assert!(matches!(DESTROY, Some(WindowMessage::Destroy)));
+        assert!(NONE.is_none());
+    }
+
+    #[test]
+    fn capture_changed_null_window() {
+        assert!(message(WM_CAPTURECHANGED, 0, 0).capture_gained().is_none());
+        assert_eq!(message(WM_CAPTURECHANGED, 0, 0x70).capture_gained(), Some(0x70 as HWND));
+    }
+
+    #[test]
+    fn compacting_ratio() {
+        assert_eq!(message(WM_COMPACTING, 0xFFFF, 0).compacting_ratio(), Some(1.0));
+        assert_eq!(message(WM_COMPACTING, 0, 0).compacting_ratio(), Some(0.0));
+    }
+
+    #[test]
+    fn iconic_thumbnail_word_order() {
+        // The high word is the width, the low word the height.
+        let thumbnail = message(WM_DWMSENDICONICTHUMBNAIL, 0, 200 << 16 | 120);
+        assert_eq!(thumbnail.requested_size(), Some((200, 120)));
+    }
+
+    #[test]
+    fn parse_with_target_unassigned_id() {
+        let unassigned = (0..WM_USER).find(|&msg| message_name(msg).is_none()).unwrap();
+        let targeted = WindowEvent::parse_with_target(0x10 as HWND, unassigned, 1, 2);
+        assert!(matches!(targeted.event, WindowEvent::Unknown(raw) if raw == RawEvent { msg: unassigned, w_param: 1, l_param: 2 }));
+        assert_eq!(targeted.raw(), RawEvent { msg: unassigned, w_param: 1, l_param: 2 });
+    }
+
+    #[test]
+    fn mouse_pos_sign() {
+        let l_param = (-100i16 as u16 as u32 | 3000 << 16) as LPARAM;
+        let pos = MousePos::from_lparam(l_param);
+        assert_eq!((pos.x, pos.y), (-100, 3000));
+        assert_eq!(pos.into_lparam(), l_param);
+        assert!(matches!(message(WM_MOUSEMOVE, 0, l_param).pos(), Some(MousePos { x: -100, y: 3000 })));
+    }
+
+    #[test]
+    fn nc_paint_entire_frame() {
+        assert!(matches!(message(WM_NCPAINT, 1, 0).nc_update_region(), Some(NcUpdateRegion::EntireFrame)));
+        assert!(matches!(message(WM_NCPAINT, 0x2A0, 0).nc_update_region(), Some(NcUpdateRegion::Region(region)) if region as WPARAM == 0x2A0));
+    }
+
+    #[test]
+    fn spooler_jobs_low_word() {
+        let status = message(WM_SPOOLERSTATUS, 1, 0x0001_0007).as_spooler_status().unwrap();
+        assert_eq!(status.jobs_remaining, 7);
+        assert_eq!(status.status, 1);
+    }
+
+    #[test]
+    fn nc_x_button2_click() {
+        let w_param = HTCAPTION as WPARAM | (XBUTTON2 as WPARAM) << 16;
+        let click = message(WM_NCXBUTTONDOWN, w_param, 0x0020_0010).as_nc_mouse_button().unwrap();
+        assert_eq!(click.action, MouseButtonAction::Down);
+        assert_eq!(click.button, MouseButton::X(XBUTTON2));
+        assert_eq!(click.hit_test, Some(HitTest::Caption));
+        assert_eq!((click.pos.x, click.pos.y), (0x10, 0x20));
+        assert!(matches!(click.space, CoordSpace::Screen));
+    }
+
+    #[test]
+    fn raw_event_try_from() {
+        let raw = RawEvent { msg: WM_KEYUP, w_param: VK_ESCAPE as WPARAM, l_param: 0 };
+        let key = KeyMessage::try_from(raw).unwrap();
+        assert!(key.up);
+        assert_eq!(key.code, VK_ESCAPE as WPARAM);
+        let raw = RawEvent { msg: WM_MOUSEMOVE, w_param: 0, l_param: 0 };
+        assert_eq!(KeyMessage::try_from(raw).unwrap_err(), UnexpectedMessage(raw));
+
+        let raw = RawEvent { msg: WM_RBUTTONDBLCLK, w_param: MK_RBUTTON as WPARAM, l_param: 0x0002_0001 };
+        let click = MouseButtonMessage::try_from(raw).unwrap();
+        assert_eq!(click.action, MouseButtonAction::DoubleClick);
+        assert_eq!(click.button, MouseButton::Right);
+        let raw = RawEvent { msg: WM_CHAR, w_param: 'a' as WPARAM, l_param: 0 };
+        assert_eq!(MouseButtonMessage::try_from(raw).unwrap_err(), UnexpectedMessage(raw));
+    }
+
+    #[test]
+    fn constructors_round_trip() {
+        fn reparse(message: WindowMessage) -> WindowMessage {
+            let raw = message.into_raw();
+            self::message(raw.msg, raw.w_param, raw.l_param)
+        }
+
+        let pos = MousePos { x: -5, y: 40 };
+        let built = WindowMessage::mouse_button(MouseButtonAction::Up, MouseButton::X(XBUTTON1), pos, Modifiers(MK_SHIFT as WPARAM));
+        let click = reparse(built).as_mouse_button().unwrap();
+        assert_eq!(click.action, MouseButtonAction::Up);
+        assert_eq!(click.button, MouseButton::X(XBUTTON1));
+        assert_eq!((click.pos.x, click.pos.y), (-5, 40));
+        assert_eq!(click.modifiers & MK_SHIFT as WPARAM, MK_SHIFT as WPARAM);
+
+        let info = KeyInfo::new(3, 0x1C, true, false, true, false);
+        let key = reparse(WindowMessage::key(VK_RETURN as WPARAM, false, true, info)).as_key().unwrap();
+        assert!(!key.up && key.sys);
+        assert_eq!(key.code, VK_RETURN as WPARAM);
+        assert_eq!((key.info.repeat_count(), key.info.scan_code()), (3, 0x1C));
+        assert!(key.info.extended() && key.info.previous_state());
+
+        let built = WindowMessage::mouse_wheel(WheelAxis::Horizontal, -2, pos, Modifiers(MK_CONTROL as WPARAM)).unwrap();
+        let wheel = reparse(built).as_mouse_wheel().unwrap();
+        assert_eq!(wheel.axis, WheelAxis::Horizontal);
+        assert_eq!(wheel.delta, -240);
+        assert!(wheel.modifiers.control());
+        assert_eq!((wheel.pos.x, wheel.pos.y), (-5, 40));
+        let wheel = reparse(WindowMessage::mouse_wheel(WheelAxis::Vertical, 273, pos, Modifiers(0)).unwrap());
+        assert_eq!(wheel.as_mouse_wheel().unwrap().delta, 273 * 120);
+        assert!(WindowMessage::mouse_wheel(WheelAxis::Vertical, 274, pos, Modifiers(0)).is_none());
+        assert!(WindowMessage::mouse_wheel(WheelAxis::Vertical, -274, pos, Modifiers(0)).is_none());
+    }
+
+    #[test]
+    fn hit_test_classify() {
+        let rect = RECT { left: 100, top: 100, right: 300, bottom: 200 };
+        for (x, y, expected) in [
+            (50, 150, HitTest::Nowhere),
+            (300, 150, HitTest::Nowhere),
+            (102, 102, HitTest::TopLeft),
+            (298, 102, HitTest::TopRight),
+            (102, 198, HitTest::BottomLeft),
+            (298, 198, HitTest::BottomRight),
+            (102, 150, HitTest::Left),
+            (298, 150, HitTest::Right),
+            (200, 102, HitTest::Top),
+            (200, 198, HitTest::Bottom),
+            (200, 110, HitTest::Caption),
+            (200, 150, HitTest::Client),
+        ] {
+            assert_eq!(HitTest::classify(MousePos { x, y }, &rect, 4, 20), expected, "({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn event_stream_filters() {
+        let events = std::vec![
+            WindowEvent::parse_checked(WM_KEYDOWN, VK_SPACE as WPARAM, 0),
+            WindowEvent::parse_checked(WM_LBUTTONDOWN, MK_LBUTTON as WPARAM, 0),
+            WindowEvent::parse_checked(WM_USER + 5, 0, 0),
+            WindowEvent::parse_checked(WM_MOUSEMOVE, 0, 0),
+            WindowEvent::parse_checked(WM_LBUTTONUP, 0, 0),
+            WindowEvent::parse_checked(WM_KEYUP, VK_SPACE as WPARAM, 0),
+        ];
+        assert_eq!(events.iter().copied().messages().count(), 5);
+        let keys: std::vec::Vec<_> = events.iter().copied().keys().map(|key| key.up).collect();
+        assert_eq!(keys, [false, true]);
+        let buttons: std::vec::Vec<_> = events.iter().copied().mouse_buttons().map(|button| button.action).collect();
+        assert_eq!(buttons, [MouseButtonAction::Down, MouseButtonAction::Up]);
+    }
+
+    #[test]
+    fn range_boundaries() {
+        let range = |msg| RawEvent { msg, w_param: 0, l_param: 0 }.range();
+        assert_eq!(range(WM_USER - 1), MessageRange::System);
+        assert_eq!(range(WM_USER), MessageRange::User);
+        assert_eq!(range(WM_APP - 1), MessageRange::User);
+        assert_eq!(range(WM_APP), MessageRange::App);
+        assert_eq!(range(0xBFFF), MessageRange::App);
+        assert_eq!(range(0xC000), MessageRange::String);
+        assert_eq!(range(0xFFFE), MessageRange::String);
+        assert_eq!(range(0xFFFF), MessageRange::Reserved);
+        assert_eq!(range(UINT::MAX), MessageRange::Reserved);
+        assert_eq!(RawEvent { msg: WM_USER + 3, w_param: 0, l_param: 0 }.user_offset(), Some(3));
+        assert_eq!(RawEvent { msg: WM_APP, w_param: 0, l_param: 0 }.user_offset(), None);
+    }
+
+    #[test]
+    fn uah_draw_messages() {
+        assert!(matches!(message(0x00AE, 1, 2), WindowMessage::NcUahDrawCaption { w: 1, l: 2, .. }));
+        assert!(matches!(message(0x00AF, 3, 4), WindowMessage::NcUahDrawFrame { w: 3, l: 4, .. }));
+        assert!(message(0x00AE, 0, 0).is_uah_draw());
+        assert!(message(0x00AF, 0, 0).is_uah_draw());
+        assert!(!message(WM_NCPAINT, 1, 0).is_uah_draw());
+    }
+
+    #[test]
+    fn title_bar_close_rect() {
+        let mut raw: TITLEBARINFOEX = unsafe { core::mem::zeroed() };
+        raw.cbSize = core::mem::size_of::<TITLEBARINFOEX>() as u32;
+        let mut message = message(WM_GETTITLEBARINFOEX, 0, &mut raw as *mut TITLEBARINFOEX as LPARAM);
+        let mut info = message.title_bar_info().unwrap();
+        info.set_rect(TitleBarPart::Close, RECT { left: 760, top: 0, right: 800, bottom: 30 });
+        let mut state = TitleBarState::default();
+        state.set_hot_tracked(true);
+        info.set_state(TitleBarPart::Close, state);
+        let rect = info.rect(TitleBarPart::Close);
+        assert_eq!((rect.left, rect.top, rect.right, rect.bottom), (760, 0, 800, 30));
+        assert!(info.state(TitleBarPart::Close).hot_tracked());
+
+        let rect = raw.rgrect[TitleBarPart::Close as usize];
+        assert_eq!((rect.left, rect.right, rect.bottom), (760, 800, 30));
+        assert_eq!(raw.rgstate[TitleBarPart::Close as usize], 1 << 7);
+        assert_eq!(raw.rgrect[TitleBarPart::Minimize as usize].right, 0);
+    }
+
+    #[test]
+    fn coord_space_tags() {
+        let client = message(WM_LBUTTONDOWN, 0, 0).as_mouse_button().unwrap();
+        assert_eq!(client.space, CoordSpace::Client);
+        let nc = message(WM_NCLBUTTONDOWN, HTCAPTION as WPARAM, 0).as_nc_mouse_button().unwrap();
+        assert_eq!(nc.space, CoordSpace::Screen);
+    }
+
+    #[test]
+    fn menu_loop_track_popup() {
+        assert_eq!(message(WM_ENTERMENULOOP, TRUE as WPARAM, 0).is_track_popup(), Some(true));
+        assert_eq!(message(WM_ENTERMENULOOP, FALSE as WPARAM, 0).is_track_popup(), Some(false));
+        assert_eq!(message(WM_EXITMENULOOP, TRUE as WPARAM, 0).is_track_popup(), Some(true));
+        assert_eq!(message(WM_EXITMENULOOP, FALSE as WPARAM, 0).is_track_popup(), Some(false));
+        assert_eq!(message(WM_INITMENU, 0, 0).is_track_popup(), None);
+    }
+
+    #[test]
+    fn activation_codes_round_trip() {
+        let codes: [u32; 4] = [MA_ACTIVATE as _, MA_ACTIVATEANDEAT as _, MA_NOACTIVATE as _, MA_NOACTIVATEANDEAT as _];
+        for code in codes {
+            assert_eq!(MouseActivation::from_code(code).map(MouseActivation::into_code), Some(code));
+        }
+        assert!(MouseActivation::from_code(0).is_none());
+        let codes: [u16; 3] = [WA_ACTIVE as _, WA_CLICKACTIVE as _, WA_INACTIVE as _];
+        for code in codes {
+            assert_eq!(WindowActivation::from_code(code).map(WindowActivation::into_code), Some(code));
+        }
+        assert!(WindowActivation::from_code(3).is_none());
+        let codes: [usize; 5] = [SIZE_RESTORED as _, SIZE_MINIMIZED as _, SIZE_MAXSHOW as _, SIZE_MAXIMIZED as _, SIZE_MAXHIDE as _];
+        for code in codes {
+            assert_eq!(WindowResizing::from_code(code).map(WindowResizing::into_code), Some(code));
+        }
+        assert!(WindowResizing::from_code(5).is_none());
+        let codes: [usize; 5] = [
+            PBT_APMPOWERSTATUSCHANGE as _,
+            PBT_APMRESUMEAUTOMATIC as _,
+            PBT_APMRESUMESUSPEND as _,
+            PBT_APMSUSPEND as _,
+            PBT_POWERSETTINGCHANGE as _
+        ];
+        for code in codes {
+            assert_eq!(PowerEvent::from_code(code).map(PowerEvent::into_code), Some(code));
+        }
+        assert!(PowerEvent::from_code(0).is_none());
+    }
+
+    #[test]
+    fn help_info_decodes() {
+        let mut raw: HELPINFO = unsafe { core::mem::zeroed() };
+        raw.cbSize = core::mem::size_of::<HELPINFO>() as _;
+        raw.iContextType = HELPINFO_WINDOW as _;
+        raw.iCtrlId = 1001;
+        raw.hItemHandle = 0x40 as HANDLE;
+        raw.MousePos.x = 320;
+        raw.MousePos.y = -12;
+        let info = message(WM_HELP, 0, &mut raw as *mut HELPINFO as LPARAM).help_info().unwrap();
+        assert_eq!(info.context_type, HelpContext::Window);
+        assert_eq!(info.ctrl_id, 1001);
+        assert_eq!(info.item_handle, 0x40 as HANDLE);
+        assert_eq!((info.pos.x, info.pos.y), (320, -12));
+
+        raw.iContextType = HELPINFO_MENUITEM as _;
+        let info = message(WM_HELP, 0, &mut raw as *mut HELPINFO as LPARAM).help_info().unwrap();
+        assert_eq!(info.context_type, HelpContext::Menu);
+        assert!(message(WM_HELP, 0, 0).help_info().is_none());
+    }
+
+    #[test]
+    fn pos_per_space() {
+        let (pos, space) = message(WM_MOUSEMOVE, 0, 0x0030_0020).pos_in_space().unwrap();
+        assert_eq!((pos.x, pos.y, space), (0x20, 0x30, CoordSpace::Client));
+        let (pos, space) = message(WM_NCHITTEST, 0, 0x0200_0100).pos_in_space().unwrap();
+        assert_eq!((pos.x, pos.y, space), (0x100, 0x200, CoordSpace::Screen));
+        let pos = message(WM_CONTEXTMENU, 0x40, -1).pos().unwrap();
+        assert_eq!((pos.x, pos.y), (-1, -1));
+        assert!(message(WM_PAINT, 0, 0).pos().is_none());
+    }
+
+    #[test]
+    fn menu_command_decodes() {
+        let menu = 0x40 as HMENU;
+        let command = message(WM_MENUCOMMAND, 3, menu as LPARAM).as_menu_command().unwrap();
+        assert_eq!(command.index, 3);
+        assert_eq!(command.menu, menu);
+    }
+
+    #[test]
+    fn alt_is_not_x_button1() {
+        let modifiers = Modifiers(MK_XBUTTON1 as WPARAM);
+        assert!(modifiers.x_button1());
+        assert!(!modifiers.alt());
+        assert!(!Modifiers(0xFFFF).alt());
+    }
+
+    #[test]
+    fn dispatch_context_pointers() {
+        let mut info: MINMAXINFO = unsafe { core::mem::zeroed() };
+        assert!(message(WM_GETMINMAXINFO, 0, &mut info as *mut MINMAXINFO as LPARAM).requires_dispatch_context());
+        let mut rect = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+        assert!(message(WM_SIZING, WMSZ_LEFT as WPARAM, &mut rect as *mut RECT as LPARAM).requires_dispatch_context());
+        assert!(!message(WM_SETFONT, 0x40, 1).requires_dispatch_context());
+        assert!(!message(WM_KEYDOWN, VK_SPACE as WPARAM, 0).requires_dispatch_context());
+        assert!(!message(WM_PAINT, 0, 0).requires_dispatch_context());
+    }
+
+    #[test]
+    fn set_font_redraw_low_word() {
+        let font = 0x40 as HFONT;
+        let set = message(WM_SETFONT, font as WPARAM, 1).as_set_font().unwrap();
+        assert_eq!(set.font, font);
+        assert!(set.redraw);
+        assert!(!message(WM_SETFONT, font as WPARAM, 0x0001_0000).as_set_font().unwrap().redraw);
+    }
+
+    #[test]
+    fn mouse_message_ids_round_trip() {
+        for button in [MouseButton::Left, MouseButton::Right, MouseButton::Middle, MouseButton::X(0)] {
+            for action in [MouseButtonAction::Down, MouseButtonAction::Up, MouseButtonAction::DoubleClick] {
+                let msg = mouse_message_id(button, action);
+                assert_eq!(mouse_button_from_id(msg), Some((button, action)));
+                let parsed = message(msg, 0, 0).as_mouse_button().unwrap();
+                assert_eq!(parsed.action, action);
+            }
+        }
+        assert_eq!(mouse_message_id(MouseButton::X(XBUTTON2), MouseButtonAction::Up), WM_XBUTTONUP);
+        assert_eq!(mouse_button_from_id(WM_MOUSEMOVE), None);
+    }
+
+    #[test]
+    fn query_drag_icon_reply() {
+        let icon = 0x40 as HICON;
+        assert_eq!(QueryDragIconReply::Icon(icon).into_lresult(), 0x40);
+        assert_eq!(QueryDragIconReply::Default.into_lresult(), 0);
+    }
+
+    #[test]
+    fn raw_event_hex_dump() {
+        let raw = RawEvent { msg: WM_USER, w_param: 1, l_param: 0x0012_FF60 };
+        #[cfg(target_pointer_width = "64")]
+        let expected = "msg=0x0400 wParam=0x0000000000000001 lParam=0x000000000012FF60";
+        #[cfg(target_pointer_width = "32")]
+        let expected = "msg=0x0400 wParam=0x00000001 lParam=0x0012FF60";
+        assert_eq!(std::format!("{}", raw), expected);
+        let event = WindowEvent::parse_checked(raw.msg, raw.w_param, raw.l_param);
+        assert_eq!(std::format!("{}", event), std::format!("User {}", expected));
+    }
+
+    #[test]
+    fn char_decoder_dead_keys() {
+        fn decode(units: &[(u16, bool)]) -> std::string::String {
+            let mut decoder = CharDecoder::new();
+            units.iter().flat_map(|&(code_unit, dead)| {
+                decoder.feed(CharMessage { code_unit, info: KeyInfo(0), sys: false, dead }).collect::<std::vec::Vec<_>>()
+            }).collect()
+        }
+
+        assert_eq!(decode(&[('^' as u16, true), ('e' as u16, false)]), "ê");
+        assert_eq!(decode(&[('^' as u16, true), (' ' as u16, false)]), "^");
+        assert_eq!(decode(&[('^' as u16, true), ('x' as u16, false)]), "^x");
+        assert_eq!(decode(&[(0xD83D, false), (0xDE00, false)]), "\u{1F600}");
+        assert_eq!(decode(&[(0xDE00, false)]), "");
+    }
+
+    #[test]
+    fn hwnd_payloads() {
+        let window = 0x40 as HWND;
+        let command = (BN_CLICKED as WPARAM) << 16 | 1001;
+        assert_eq!(message(WM_COMMAND, command, window as LPARAM).hwnd_payload(), Some(window));
+        assert_eq!(message(WM_COMMAND, 1001, 0).hwnd_payload(), None);
+        assert_eq!(message(WM_CTLCOLOREDIT, 0x80, window as LPARAM).hwnd_payload(), Some(window));
+        assert_eq!(message(WM_CTLCOLORSTATIC, 0x80, window as LPARAM).hwnd_payload(), Some(window));
+        assert_eq!(message(WM_ACTIVATE, WA_ACTIVE as WPARAM, window as LPARAM).hwnd_payload(), Some(window));
+        assert_eq!(message(WM_SETFOCUS, window as WPARAM, 0).hwnd_payload(), Some(window));
+        assert_eq!(message(WM_SETFOCUS, 0, 0).hwnd_payload(), None);
+        let created = (WM_CREATE as WPARAM) | 7 << 16;
+        assert_eq!(message(WM_PARENTNOTIFY, created, window as LPARAM).hwnd_payload(), Some(window));
+        let destroyed = (WM_DESTROY as WPARAM) | 7 << 16;
+        assert_eq!(message(WM_PARENTNOTIFY, destroyed, window as LPARAM).hwnd_payload(), Some(window));
+        assert_eq!(message(WM_PARENTNOTIFY, WM_LBUTTONDOWN as WPARAM, 0x0010_0010).hwnd_payload(), None);
+        assert_eq!(message(WM_PAINT, 0, 0).hwnd_payload(), None);
+    }
+
+    #[test]
+    fn key_menu_accel_char() {
+        let alt_f = message(WM_SYSCOMMAND, SC_KEYMENU as WPARAM, 'f' as LPARAM).as_sys_command().unwrap();
+        assert_eq!(alt_f.command, SystemCommand::KeyMenu { accel_char: Some('f') });
+        assert_eq!(alt_f.command.accel_char(), Some('f'));
+        let alt = message(WM_SYSCOMMAND, SC_KEYMENU as WPARAM, 0).as_sys_command().unwrap();
+        assert_eq!(alt.command, SystemCommand::KeyMenu { accel_char: None });
+        let close = message(WM_SYSCOMMAND, SC_CLOSE as WPARAM | 0x0003, 0x0010_0020).as_sys_command().unwrap();
+        assert_eq!(close.command, SystemCommand::Close);
+        assert_eq!(close.command.accel_char(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn window_proc_routes_messages() {
+        use std::{cell::RefCell, rc::Rc, string::String, vec::Vec};
+
+        let log = Rc::new(RefCell::new(Vec::<String>::new()));
+        let (keys, mice, others) = (log.clone(), log.clone(), log.clone());
+        let mut proc = WindowProcBuilder::new()
+            .on_key(move |key| {
+                keys.borrow_mut().push(std::format!("{}", key));
+                (!key.up).then_some(0)
+            })
+            .on_mouse(move |mouse| {
+                mice.borrow_mut().push(std::format!("{:?} {:?}", mouse.action, mouse.button));
+                Some(0)
+            })
+            .on_other(move |event| {
+                others.borrow_mut().push(std::format!("{}", event));
+                None
+            })
+            .build();
+
+        let window = core::ptr::null_mut();
+        assert_eq!(proc.dispatch(window, WM_KEYDOWN, VK_RETURN as WPARAM, 0), Some(0));
+        assert_eq!(proc.dispatch(window, WM_LBUTTONDOWN, MK_LBUTTON as WPARAM, 0x0010_0010), Some(0));
+        assert_eq!(proc.dispatch(window, WM_MOUSEMOVE, 0, 0), None);
+        assert_eq!(proc.dispatch(window, WM_USER + 1, 2, 3), None);
+        assert_eq!(proc.dispatch(window, WM_KEYUP, VK_RETURN as WPARAM, 0), None);
+
+        let log = log.borrow();
+        assert_eq!(log.len(), 5);
+        assert_eq!(log[0], "KeyDown Return");
+        assert_eq!(log[1], "Down Left");
+        assert!(log[2].starts_with("WM_MOUSEMOVE "));
+        assert!(log[3].starts_with("User msg=0x0401 "));
+        assert_eq!(log[4], "KeyUp Return");
+    }
+
+    #[test]
+    fn range_constructors() {
+        let reparse = |event: WindowEvent| {
+            let raw = event.into_raw();
+            WindowEvent::parse(raw.msg, raw.w_param, raw.l_param)
+        };
+        let user = WindowEvent::user(7, 1, 2).unwrap();
+        assert!(matches!(reparse(user), WindowEvent::User(RawEvent { msg: 7, w_param: 1, l_param: 2 })));
+        assert_eq!(user.into_raw().msg, WM_USER + 7);
+        let app = WindowEvent::app(0x3FFF, 3, 4).unwrap();
+        assert!(matches!(reparse(app), WindowEvent::App(RawEvent { msg: 0x3FFF, w_param: 3, l_param: 4 })));
+        let string = WindowEvent::string(0xC123, 5, 6).unwrap();
+        assert!(matches!(reparse(string), WindowEvent::String(raw) if raw == RawEvent { msg: 0xC123 - WM_APP, w_param: 5, l_param: 6 }));
+        assert_eq!(string.into_raw().msg, 0xC123);
+
+        assert!(WindowEvent::user(WM_APP - WM_USER, 0, 0).is_none());
+        assert!(WindowEvent::user(0x7C00, 0, 0).is_none());
+        assert!(WindowEvent::user(UINT::MAX, 0, 0).is_none());
+        assert!(WindowEvent::app(0xC000 - WM_APP, 0, 0).is_none());
+        assert!(WindowEvent::app(UINT::MAX, 0, 0).is_none());
+        assert!(WindowEvent::string(0x100, 0, 0).is_none());
+        assert!(WindowEvent::string(0xBFFF, 0, 0).is_none());
+        assert!(WindowEvent::string(0xFFFF, 0, 0).is_none());
+    }
+
+    #[test]
+    fn colorization_channels() {
+        let colorization = message(WM_DWMCOLORIZATIONCOLORCHANGED, 0xC0_12_34_56, TRUE as LPARAM).as_colorization().unwrap();
+        let color = colorization.color;
+        assert_eq!((color.alpha(), color.red(), color.green(), color.blue()), (0xC0, 0x12, 0x34, 0x56));
+        assert_eq!(u32::from(color), 0xC0_12_34_56);
+        assert!(colorization.opaque_blend);
+        assert!(!message(WM_DWMCOLORIZATIONCOLORCHANGED, 0, FALSE as LPARAM).as_colorization().unwrap().opaque_blend);
+    }
+}
@@ -0,0 +1,232 @@
+#[cfg(not(any(feature = "winapi", feature = "windows-sys")))]
+compile_error!("either the `winapi` or the `windows-sys` feature must be enabled");
+
+#[cfg(not(feature = "windows-sys"))]
+mod backend {
+    #[cfg(feature = "std")]
+    pub use winapi::shared::guiddef::GUID;
+    pub use winapi::shared::minwindef::*;
+    pub use winapi::shared::windef::*;
+    pub use winapi::um::shellapi::HDROP;
+    #[cfg(feature = "std")]
+    pub use winapi::um::shellapi::{DragFinish, DragQueryFileW, DragQueryPoint};
+    #[cfg(feature = "std")]
+    pub use winapi::um::winbase::{GlobalLock, GlobalUnlock};
+    pub use winapi::um::winnt::HANDLE;
+    pub use winapi::um::winuser::*;
+
+    pub use crate::ffi::*;
+
+    #[cfg(feature = "std")]
+    pub unsafe fn raw_mouse(input: &RAWINPUT) -> &RAWMOUSE {
+        input.data.mouse()
+    }
+
+    #[cfg(feature = "std")]
+    pub unsafe fn raw_keyboard(input: &RAWINPUT) -> &RAWKEYBOARD {
+        input.data.keyboard()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn raw_mouse_buttons(mouse: &RAWMOUSE) -> (USHORT, USHORT) {
+        (mouse.usButtonFlags, mouse.usButtonData)
+    }
+}
+
+#[cfg(feature = "windows-sys")]
+#[allow(clippy::upper_case_acronyms)]
+mod backend {
+    use windows_sys::Win32::System::SystemServices as ss;
+    use windows_sys::Win32::UI::Input::Pointer as pointer;
+    use windows_sys::Win32::UI::WindowsAndMessaging as wm;
+
+    #[cfg(feature = "std")]
+    pub use windows_sys::core::GUID;
+    pub use windows_sys::Win32::Foundation::*;
+    pub use windows_sys::Win32::Graphics::Gdi::*;
+    pub use windows_sys::Win32::System::DataExchange::COPYDATASTRUCT;
+    #[cfg(feature = "std")]
+    pub use windows_sys::Win32::System::Memory::{GlobalLock, GlobalUnlock};
+    #[cfg(feature = "std")]
+    pub use windows_sys::Win32::System::DataExchange::{GetClipboardSequenceNumber, IsClipboardFormatAvailable};
+    #[cfg(feature = "std")]
+    pub use windows_sys::Win32::System::Ole::{CF_HDROP, CF_UNICODETEXT};
+    pub use windows_sys::Win32::System::Power::*;
+    pub use windows_sys::Win32::System::SystemServices::{
+        APPCOMMAND_BROWSER_BACKWARD,
+        APPCOMMAND_BROWSER_FORWARD,
+        APPCOMMAND_BROWSER_REFRESH,
+        APPCOMMAND_BROWSER_STOP,
+        APPCOMMAND_BROWSER_SEARCH,
+        APPCOMMAND_BROWSER_FAVORITES,
+        APPCOMMAND_BROWSER_HOME,
+        APPCOMMAND_VOLUME_MUTE,
+        APPCOMMAND_VOLUME_DOWN,
+        APPCOMMAND_VOLUME_UP,
+        APPCOMMAND_MEDIA_NEXTTRACK,
+        APPCOMMAND_MEDIA_PREVIOUSTRACK,
+        APPCOMMAND_MEDIA_STOP,
+        APPCOMMAND_MEDIA_PLAY_PAUSE,
+        APPCOMMAND_LAUNCH_MAIL,
+        APPCOMMAND_LAUNCH_MEDIA_SELECT,
+        APPCOMMAND_LAUNCH_APP1,
+        APPCOMMAND_LAUNCH_APP2,
+        APPCOMMAND_BASS_DOWN,
+        APPCOMMAND_BASS_BOOST,
+        APPCOMMAND_BASS_UP,
+        APPCOMMAND_TREBLE_DOWN,
+        APPCOMMAND_TREBLE_UP,
+        APPCOMMAND_MICROPHONE_VOLUME_MUTE,
+        APPCOMMAND_MICROPHONE_VOLUME_DOWN,
+        APPCOMMAND_MICROPHONE_VOLUME_UP,
+        APPCOMMAND_HELP,
+        APPCOMMAND_FIND,
+        APPCOMMAND_NEW,
+        APPCOMMAND_OPEN,
+        APPCOMMAND_CLOSE,
+        APPCOMMAND_SAVE,
+        APPCOMMAND_PRINT,
+        APPCOMMAND_UNDO,
+        APPCOMMAND_REDO,
+        APPCOMMAND_COPY,
+        APPCOMMAND_CUT,
+        APPCOMMAND_PASTE,
+        APPCOMMAND_REPLY_TO_MAIL,
+        APPCOMMAND_FORWARD_MAIL,
+        APPCOMMAND_SEND_MAIL,
+        APPCOMMAND_SPELL_CHECK,
+        APPCOMMAND_DICTATE_OR_COMMAND_CONTROL_TOGGLE,
+        APPCOMMAND_MIC_ON_OFF_TOGGLE,
+        APPCOMMAND_CORRECTION_LIST,
+        APPCOMMAND_MEDIA_PLAY,
+        APPCOMMAND_MEDIA_PAUSE,
+        APPCOMMAND_MEDIA_RECORD,
+        APPCOMMAND_MEDIA_FAST_FORWARD,
+        APPCOMMAND_MEDIA_REWIND,
+        APPCOMMAND_MEDIA_CHANNEL_UP,
+        APPCOMMAND_MEDIA_CHANNEL_DOWN,
+        APPCOMMAND_DELETE,
+        APPCOMMAND_DWM_FLIP3D
+    };
+    pub use windows_sys::Win32::UI::Input::*;
+    pub use windows_sys::Win32::UI::Input::Ime::{
+        IMN_CLOSESTATUSWINDOW,
+        IMN_OPENSTATUSWINDOW,
+        IMN_CHANGECANDIDATE,
+        IMN_CLOSECANDIDATE,
+        IMN_OPENCANDIDATE,
+        IMN_SETCONVERSIONMODE,
+        IMN_SETSENTENCEMODE,
+        IMN_SETOPENSTATUS,
+        IMN_SETCANDIDATEPOS,
+        IMN_SETCOMPOSITIONFONT,
+        IMN_SETCOMPOSITIONWINDOW,
+        IMN_SETSTATUSWINDOWPOS,
+        IMN_GUIDELINE,
+        IMN_PRIVATE,
+        IMN_SOFTKBDDESTROYED
+    };
+    pub use windows_sys::Win32::UI::Input::KeyboardAndMouse::*;
+    #[cfg(feature = "std")]
+    pub use windows_sys::Win32::UI::Input::Pointer::*;
+    pub use windows_sys::Win32::UI::Input::Touch::*;
+    pub use windows_sys::Win32::UI::Shell::{HDROP, HELPINFO, HELPINFO_MENUITEM, HELPINFO_WINDOW};
+    #[cfg(feature = "std")]
+    pub use windows_sys::Win32::UI::Shell::{DragFinish, DragQueryFileW, DragQueryPoint};
+    pub use windows_sys::Win32::UI::WindowsAndMessaging::*;
+    #[cfg(feature = "std")]
+    pub use windows_sys::Win32::UI::Accessibility::LresultFromObject;
+    pub use windows_sys::Win32::UI::Controls::{WM_MOUSEHOVER, WM_MOUSELEAVE};
+
+    pub type WORD = u16;
+    #[cfg(feature = "std")]
+    pub type USHORT = u16;
+    pub type UINT = u32;
+    pub type DWORD = u32;
+
+    pub const MK_CONTROL: WPARAM = ss::MK_CONTROL as _;
+    pub const MK_LBUTTON: WPARAM = ss::MK_LBUTTON as _;
+    pub const MK_MBUTTON: WPARAM = ss::MK_MBUTTON as _;
+    pub const MK_RBUTTON: WPARAM = ss::MK_RBUTTON as _;
+    pub const MK_SHIFT: WPARAM = ss::MK_SHIFT as _;
+    pub const MK_XBUTTON1: WPARAM = ss::MK_XBUTTON1 as _;
+    pub const MK_XBUTTON2: WPARAM = ss::MK_XBUTTON2 as _;
+
+    pub const WA_ACTIVE: WORD = wm::WA_ACTIVE as _;
+    pub const WA_CLICKACTIVE: WORD = wm::WA_CLICKACTIVE as _;
+    pub const WA_INACTIVE: WORD = wm::WA_INACTIVE as _;
+
+    pub const SW_OTHERUNZOOM: LPARAM = wm::SW_OTHERUNZOOM as _;
+    pub const SW_OTHERZOOM: LPARAM = wm::SW_OTHERZOOM as _;
+    pub const SW_PARENTCLOSING: LPARAM = wm::SW_PARENTCLOSING as _;
+    pub const SW_PARENTOPENING: LPARAM = wm::SW_PARENTOPENING as _;
+
+    pub const SIZE_MAXHIDE: WPARAM = wm::SIZE_MAXHIDE as _;
+    pub const SIZE_MAXIMIZED: WPARAM = wm::SIZE_MAXIMIZED as _;
+    pub const SIZE_MAXSHOW: WPARAM = wm::SIZE_MAXSHOW as _;
+    pub const SIZE_MINIMIZED: WPARAM = wm::SIZE_MINIMIZED as _;
+    pub const SIZE_RESTORED: WPARAM = wm::SIZE_RESTORED as _;
+
+    pub const PBT_APMPOWERSTATUSCHANGE: WPARAM = wm::PBT_APMPOWERSTATUSCHANGE as _;
+    pub const PBT_APMRESUMEAUTOMATIC: WPARAM = wm::PBT_APMRESUMEAUTOMATIC as _;
+    pub const PBT_APMRESUMESUSPEND: WPARAM = wm::PBT_APMRESUMESUSPEND as _;
+    pub const PBT_APMSUSPEND: WPARAM = wm::PBT_APMSUSPEND as _;
+    pub const PBT_POWERSETTINGCHANGE: WPARAM = wm::PBT_POWERSETTINGCHANGE as _;
+
+    #[cfg(feature = "std")]
+    pub const GC_ZOOM: DWORD = ss::GC_ZOOM as _;
+    #[cfg(feature = "std")]
+    pub const GC_PAN: DWORD = ss::GC_PAN as _;
+    #[cfg(feature = "std")]
+    pub const GC_ROTATE: DWORD = ss::GC_ROTATE as _;
+    #[cfg(feature = "std")]
+    pub const GC_TWOFINGERTAP: DWORD = ss::GC_TWOFINGERTAP as _;
+    #[cfg(feature = "std")]
+    pub const GC_PRESSANDTAP: DWORD = ss::GC_PRESSANDTAP as _;
+
+    pub const RIM_INPUT: WPARAM = wm::RIM_INPUT as _;
+    pub const RIM_INPUTSINK: WPARAM = wm::RIM_INPUTSINK as _;
+    #[cfg(feature = "std")]
+    pub const RI_MOUSE_WHEEL: USHORT = wm::RI_MOUSE_WHEEL as _;
+
+    pub const PT_POINTER: DWORD = wm::PT_POINTER as _;
+    pub const PT_TOUCH: DWORD = wm::PT_TOUCH as _;
+    pub const PT_PEN: DWORD = wm::PT_PEN as _;
+    pub const PT_MOUSE: DWORD = wm::PT_MOUSE as _;
+    pub const PT_TOUCHPAD: DWORD = wm::PT_TOUCHPAD as _;
+
+    pub const POINTER_CHANGE_NONE: DWORD = pointer::POINTER_CHANGE_NONE as _;
+    pub const POINTER_CHANGE_FIRSTBUTTON_DOWN: DWORD = pointer::POINTER_CHANGE_FIRSTBUTTON_DOWN as _;
+    pub const POINTER_CHANGE_FIRSTBUTTON_UP: DWORD = pointer::POINTER_CHANGE_FIRSTBUTTON_UP as _;
+    pub const POINTER_CHANGE_SECONDBUTTON_DOWN: DWORD = pointer::POINTER_CHANGE_SECONDBUTTON_DOWN as _;
+    pub const POINTER_CHANGE_SECONDBUTTON_UP: DWORD = pointer::POINTER_CHANGE_SECONDBUTTON_UP as _;
+    pub const POINTER_CHANGE_THIRDBUTTON_DOWN: DWORD = pointer::POINTER_CHANGE_THIRDBUTTON_DOWN as _;
+    pub const POINTER_CHANGE_THIRDBUTTON_UP: DWORD = pointer::POINTER_CHANGE_THIRDBUTTON_UP as _;
+    pub const POINTER_CHANGE_FOURTHBUTTON_DOWN: DWORD = pointer::POINTER_CHANGE_FOURTHBUTTON_DOWN as _;
+    pub const POINTER_CHANGE_FOURTHBUTTON_UP: DWORD = pointer::POINTER_CHANGE_FOURTHBUTTON_UP as _;
+    pub const POINTER_CHANGE_FIFTHBUTTON_DOWN: DWORD = pointer::POINTER_CHANGE_FIFTHBUTTON_DOWN as _;
+    pub const POINTER_CHANGE_FIFTHBUTTON_UP: DWORD = pointer::POINTER_CHANGE_FIFTHBUTTON_UP as _;
+
+    #[allow(non_snake_case)]
+    pub fn GET_RAWINPUT_CODE_WPARAM(w_param: WPARAM) -> WPARAM {
+        w_param & 0xff
+    }
+
+    #[cfg(feature = "std")]
+    pub unsafe fn raw_mouse(input: &RAWINPUT) -> &RAWMOUSE {
+        &input.data.mouse
+    }
+
+    #[cfg(feature = "std")]
+    pub unsafe fn raw_keyboard(input: &RAWINPUT) -> &RAWKEYBOARD {
+        &input.data.keyboard
+    }
+
+    #[cfg(feature = "std")]
+    pub fn raw_mouse_buttons(mouse: &RAWMOUSE) -> (USHORT, USHORT) {
+        let buttons = unsafe { mouse.Anonymous.Anonymous };
+        (buttons.usButtonFlags, buttons.usButtonData)
+    }
+}
+
+pub use backend::*;